@@ -0,0 +1,51 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// The answer to one part of a puzzle, as reported by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String)
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        })*
+    }
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(i) => Answer::Number(i),
+            Err(_) => Answer::Text(n.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(t) => write!(f, "{}", t)
+        }
+    }
+}
\ No newline at end of file
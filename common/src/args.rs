@@ -0,0 +1,77 @@
+use std::str::FromStr;
+use crate::Format;
+use crate::input;
+
+pub struct Args {
+    pub filename: String,
+    pub format: Format,
+    extra: Vec<(&'static str, String)>,
+    options: Vec<(String, Option<String>)>
+}
+
+impl Args {
+    /// Reads the filename and extra named arguments, in order, along with an optional `--format text|json`
+    /// anywhere among them. The filename may be left out, or given as `-`, to read from stdin. Any other
+    /// argument starting with `--` is an option, either a flag such as `--strict` or `--name=value`, and
+    /// must be one of `option_names`, so that a mistyped option is a usage error rather than ignored.
+    pub fn new(args: Vec<String>, extra_names: &[&'static str], option_names: &[&'static str]) -> Result<Self, String> {
+        let mut format = Format::Text;
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut iter = args.into_iter();
+        while let Some(arg) = iter.next() {
+            if arg == "--format" {
+                format = iter.next().ok_or("Missing value for --format")?.parse()?;
+            } else if let Some(option) = arg.strip_prefix("--") {
+                let (name, value) = match option.split_once("=") {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (option, None)
+                };
+                if !option_names.contains(&name) {
+                    return Err(format!("Unknown option: --{}\n{}", name, Self::usage(extra_names, option_names)));
+                }
+                options.push((name.to_string(), value));
+            } else {
+                positional.push(arg);
+            }
+        }
+        if positional.len() == extra_names.len() {
+            positional.insert(0, input::STDIN.to_string());
+        }
+        if positional.len() != extra_names.len() + 1 {
+            return Err(Self::usage(extra_names, option_names));
+        }
+        let mut iter = positional.into_iter();
+        let filename = iter.next().unwrap();
+        let extra = extra_names.iter().copied().zip(iter).collect();
+        Ok(Self { filename, format, extra, options })
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        let (_, value) = self.extra.iter().find(|(n, _)| *n == name)
+            .ok_or(format!("Unknown argument: {}", name))?;
+        value.parse().map_err(|_| format!("Invalid {}: {}", name, value))
+    }
+
+    /// Whether a flag such as `--strict` was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(n, _)| n == name)
+    }
+
+    /// The value of an option such as `--max-depth=100`, if it was given.
+    pub fn option<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.options.iter().find(|(n, _)| n == name) {
+            Some((_, Some(value))) => value.parse().map(Some).map_err(|_| format!("Invalid --{}: {}", name, value)),
+            Some((_, None)) => Err(format!("Missing value for --{}", name)),
+            None => Ok(None)
+        }
+    }
+
+    fn usage(extra_names: &[&'static str], option_names: &[&'static str]) -> String {
+        let mut names = vec!["[Filename]".to_string()];
+        names.extend(extra_names.iter().map(|name| name.to_string()));
+        names.push("[--format text|json]".to_string());
+        names.extend(option_names.iter().map(|name| format!("[--{}]", name)));
+        format!("Usage: {}\nWithout a filename, or with -, input is read from stdin", names.join(" "))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+/// A position in puzzle input, and the text found there. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub text: String
+}
+
+impl Span {
+    /// A span covering `token` on the first line of `line`. The token should be a slice of the line,
+    /// otherwise the span starts at the first column.
+    pub fn new(line: &str, token: &str) -> Self {
+        let start = line.as_ptr() as usize;
+        let position = token.as_ptr() as usize;
+        let column = if position >= start && position + token.len() <= start + line.len() {
+            line[..position - start].chars().count() + 1
+        } else {
+            1
+        };
+        Self {
+            line: 1,
+            column,
+            text: token.to_string()
+        }
+    }
+
+    /// A span covering a whole line.
+    pub fn line(line: &str) -> Self {
+        Self::new(line, line)
+    }
+
+    /// A span covering a single character at a 0-based char index within a line.
+    pub fn char_at(line: &str, index: usize) -> Self {
+        Self {
+            line: 1,
+            column: index + 1,
+            text: line.chars().nth(index).map(|c| c.to_string()).unwrap_or_default()
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+/// An error that may point at the part of the input which caused it.
+pub trait Diagnostic: Display {
+    fn span(&self) -> Option<&Span>;
+
+    fn span_mut(&mut self) -> Option<&mut Span>;
+
+    /// The name and text of the file the error was found in, if that isn't the puzzle input.
+    fn file(&self) -> Option<(&str, &str)> {
+        None
+    }
+
+    /// Moves the error down by a number of lines, for errors found while parsing part of a larger input.
+    fn offset_lines(mut self, lines: usize) -> Self where Self: Sized {
+        if let Some(span) = self.span_mut() {
+            span.line += lines;
+        }
+        self
+    }
+}
+
+impl Diagnostic for String {
+    fn span(&self) -> Option<&Span> {
+        None
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        None
+    }
+}
+
+impl<'a, E: Diagnostic + 'a> From<E> for Box<dyn Diagnostic + 'a> {
+    fn from(e: E) -> Self {
+        Box::new(e)
+    }
+}
+
+/// An error in a file other than the puzzle input, such as one named by an option, which is rendered
+/// against that file instead.
+pub struct FileError {
+    error: Box<dyn Diagnostic>,
+    filename: String,
+    text: String
+}
+
+impl FileError {
+    pub fn new(error: impl Diagnostic + 'static, filename: &str, text: String) -> Self {
+        Self { error: Box::new(error), filename: filename.to_string(), text }
+    }
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Diagnostic for FileError {
+    fn span(&self) -> Option<&Span> {
+        self.error.span()
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        self.error.span_mut()
+    }
+
+    fn file(&self) -> Option<(&str, &str)> {
+        Some((&self.filename, &self.text))
+    }
+}
+
+impl From<&str> for Box<dyn Diagnostic> {
+    fn from(message: &str) -> Self {
+        Box::new(message.to_string())
+    }
+}
+
+/// Formats an error in the style of a compiler diagnostic, quoting the offending line of the input:
+///
+/// ```text
+/// error: expected a number, found `x`
+///  --> day5/input.txt:3:8
+///   |
+/// 3 | 0,9 -> x,9
+///   |        ^
+/// ```
+pub fn render(error: &dyn Diagnostic, filename: &str, text: &str) -> String {
+    let (filename, text) = error.file().unwrap_or((filename, text));
+    let mut output = format!("error: {}", error);
+    if let Some(span) = error.span() {
+        output += &format!("\n --> {}:{}:{}", filename, span.line, span.column);
+        if let Some(line) = text.lines().nth(span.line.wrapping_sub(1)) {
+            let number = span.line.to_string();
+            let gutter = " ".repeat(number.len());
+            let padding = " ".repeat(span.column - 1);
+            let underline = "^".repeat(span.text.chars().count().max(1));
+            output += &format!("\n{} |\n{} | {}\n{} | {}{}", gutter, number, line.trim_end(), gutter, padding, underline);
+        }
+    }
+    output
+}
\ No newline at end of file
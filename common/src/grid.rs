@@ -0,0 +1,250 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::{Index, IndexMut};
+use crate::{input, Diagnostic, Span};
+
+/// The offsets to the four orthogonal neighbours of a cell: up, left, right and down.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The offsets to all eight neighbours of a cell, in reading order.
+const SURROUNDING: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A cell in a grid, counting columns from the left and rows from the top.
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize
+}
+
+/// A rectangular 2D map, stored row by row. A wrapping grid is toroidal: stepping off one edge
+/// comes back on at the opposite edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrapping: bool
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A character which the cell parser didn't accept
+    InvalidCell(Span),
+    /// A row with a different number of cells to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// Input without any rows
+    Empty
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The Manhattan distance to another position.
+    pub fn distance(&self, other: &Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `cell` for every position, row by row.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| Position { x, y })).map(&mut cell).collect();
+        Self {
+            cells,
+            width,
+            height,
+            wrapping: false
+        }
+    }
+
+    /// Parses a map with one character per cell, such as:
+    ///
+    /// ```text
+    /// 2199943210
+    /// 3987894921
+    /// ```
+    ///
+    /// `cell` converts each character, returning `None` for characters which aren't allowed. Every row
+    /// must be as long as the first, and there must be at least one row.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in input::lines(text).enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or_else(|| GridError::InvalidCell(Span::char_at(line, x).on_line(y + 1)))?);
+                row_width += 1;
+            }
+            if y > 0 && row_width != width {
+                return Err(GridError::InvalidRowLength { span: Span::line(line).on_line(y + 1), expected: width });
+            }
+            width = row_width;
+            height = y + 1;
+        }
+        if height == 0 {
+            return Err(GridError::Empty);
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+            wrapping: false
+        })
+    }
+
+    /// Checks a map in the format `parse` reads, returning every problem rather than stopping at the first:
+    /// each character `cell` doesn't accept, and each row of the wrong length.
+    pub fn validate(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Vec<GridError> {
+        let mut errors = Vec::new();
+        let mut width = None;
+        for (y, line) in input::lines(text).enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if cell(c).is_none() {
+                    errors.push(GridError::InvalidCell(Span::char_at(line, x).on_line(y + 1)));
+                }
+            }
+            let row_width = line.chars().count();
+            match width {
+                Some(expected) if row_width != expected => errors.push(GridError::InvalidRowLength { span: Span::line(line).on_line(y + 1), expected }),
+                Some(_) => (),
+                None => width = Some(row_width)
+            }
+        }
+        if width.is_none() {
+            errors.push(GridError::Empty);
+        }
+        errors
+    }
+
+    /// Makes the grid toroidal, or not, so that neighbours and offsets wrap around its edges.
+    pub fn wrapping(mut self, wrapping: bool) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if position.x < self.width && position.y < self.height {
+            Some(&self.cells[position.y * self.width + position.x])
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    /// Every cell in the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position a number of columns and rows away, if that's still inside the grid. The position
+    /// itself may be outside the grid, such as when looking around the edge of an infinite image.
+    pub fn offset(&self, position: Position, dx: isize, dy: isize) -> Option<Position> {
+        let x = Self::step(position.x, dx, self.width, self.wrapping)?;
+        let y = Self::step(position.y, dy, self.height, self.wrapping)?;
+        Some(Position { x, y })
+    }
+
+    /// The up to four neighbours directly up, left, right and down from a position.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&(dx, dy)| self.offset(position, dx, dy))
+    }
+
+    /// The up to eight neighbours surrounding a position, including diagonals, in reading order.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING.iter().filter_map(move |&(dx, dy)| self.offset(position, dx, dy))
+    }
+
+    /// A grid of the same shape, with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            wrapping: self.wrapping
+        }
+    }
+
+    fn step(value: usize, delta: isize, size: usize, wrapping: bool) -> Option<usize> {
+        let stepped = value as isize + delta;
+        if wrapping && size > 0 {
+            Some(stepped.rem_euclid(size as isize) as usize)
+        } else if stepped >= 0 && (stepped as usize) < size {
+            Some(stepped as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(position.x < self.width && position.y < self.height, "position {:?} outside {}x{} grid", position, self.width, self.height);
+        &self.cells[position.y * self.width + position.x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(position.x < self.width && position.y < self.height, "position {:?} outside {}x{} grid", position, self.width, self.height);
+        &mut self.cells[position.y * self.width + position.x]
+    }
+}
+
+/// Renders each row of cells on its own line, in the same layout that `parse` reads.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            GridError::InvalidCell(span) => write!(f, "unexpected `{}` in grid", span.text),
+            GridError::InvalidRowLength { span, expected } => write!(f, "expected {} cells in row, found `{}`", expected, span.text),
+            GridError::Empty => write!(f, "expected at least one row")
+        }
+    }
+}
+
+impl Diagnostic for GridError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            GridError::InvalidCell(span) => Some(span),
+            GridError::InvalidRowLength { span, .. } => Some(span),
+            GridError::Empty => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            GridError::InvalidCell(span) => Some(span),
+            GridError::InvalidRowLength { span, .. } => Some(span),
+            GridError::Empty => None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use std::fs;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+use crate::Diagnostic;
+
+/// The filename which stands for stdin.
+pub const STDIN: &str = "-";
+
+/// Reads puzzle input from a file, or from stdin if the filename is `-`.
+pub fn read(filename: &str) -> io::Result<String> {
+    if filename == STDIN {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(filename)
+    }
+}
+
+/// Opens puzzle input to be read a line at a time, from a file, or from stdin if the filename is `-`.
+pub fn open(filename: &str) -> io::Result<Box<dyn BufRead>> {
+    if filename == STDIN {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(fs::File::open(filename)?)))
+    }
+}
+
+/// The name to show for an input file in messages.
+pub fn name(filename: &str) -> &str {
+    if filename == STDIN {
+        "<stdin>"
+    } else {
+        filename
+    }
+}
+
+/// Iterates over the lines of some puzzle input, accepting both `\n` and `\r\n` line endings.
+/// Trailing whitespace is removed from every line, and blank lines at the end of the input are skipped.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.trim_end().lines().map(|l| l.trim_end())
+}
+
+/// Parses every line of some puzzle input, numbering the lines of any error.
+pub fn parse_lines<T, E>(text: &str) -> Result<Vec<T>, E> where T: FromStr<Err = E>, E: Diagnostic {
+    lines(text).enumerate().map(|(i, l)| l.parse().map_err(|e: E| e.offset_lines(i))).collect()
+}
+
+/// Something parsed from a line (or section) of puzzle input, which can list every problem with it rather
+/// than stopping at the first as parsing does. A line of a single field has at most the one problem
+/// parsing finds, which is the default.
+pub trait Validate: FromStr {
+    fn problems(line: &str) -> Vec<Self::Err> {
+        line.parse::<Self>().err().into_iter().collect()
+    }
+}
+
+/// Checks every line of some puzzle input, returning every problem with each line rather than stopping
+/// at the first.
+pub fn validate_lines<T, E>(text: &str) -> Vec<E> where T: Validate + FromStr<Err = E>, E: Diagnostic {
+    lines(text).enumerate().flat_map(|(i, l)| T::problems(l).into_iter().map(move |e| e.offset_lines(i))).collect()
+}
+
+/// Splits puzzle input into sections separated by one or more blank lines, accepting both `\n` and
+/// `\r\n` line endings. Sections keep their own line endings, so should be read with `lines`.
+pub fn sections(text: &str) -> Vec<&str> {
+    numbered_sections(text).into_iter().map(|(_, s)| s).collect()
+}
+
+/// As `sections`, but paired with the number of lines preceding each section in the input.
+pub fn numbered_sections(text: &str) -> Vec<(usize, &str)> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((n, s)) = start.take() {
+                sections.push((n, &text[s..end]));
+            }
+        } else {
+            if start.is_none() {
+                start = Some((i, offset));
+            }
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+    if let Some((n, s)) = start {
+        sections.push((n, &text[s..end]));
+    }
+    sections
+}
+
+/// Parses a numbered section, numbering the lines of any error relative to the whole input.
+pub fn parse_section<T, E>(section: (usize, &str)) -> Result<T, E> where T: FromStr<Err = E>, E: Diagnostic {
+    let (offset, text) = section;
+    text.parse().map_err(|e: E| e.offset_lines(offset))
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::env;
+use std::io::BufRead;
+use std::process;
+use std::time::Instant;
+
+mod args;
+mod answer;
+mod diagnostic;
+mod random;
+mod report;
+pub mod grid;
+pub mod input;
+
+pub use args::Args;
+pub use answer::Answer;
+pub use diagnostic::{Diagnostic, FileError, Span, render};
+pub use grid::{Grid, GridError, Position};
+pub use random::{Rng, assert_round_trip};
+pub use report::{Format, Report, Solution};
+
+#[cfg(test)]
+mod tests;
+
+/// Runs a puzzle binary: reads the filename (and any extra named arguments and `--` options, which must
+/// be among `option_names`) from the command line,
+/// loads that file (or stdin) and hands its contents to `solve`, which records its results in a `Report`.
+/// The report is then printed as text, or as JSON with `--format json`.
+/// Usage errors exit with code 2, and a failed read or solve exits with code 1 after printing a diagnostic.
+/// A part which fails is reported along with the rest, then also exits with code 1.
+pub fn run<F>(day: usize, extra_names: &[&'static str], option_names: &[&'static str], solve: F) where F: FnOnce(&str, &Args, &mut Report) -> Result<(), Box<dyn Diagnostic>> {
+    let args = parse_args(extra_names, option_names);
+    let name = input::name(&args.filename);
+    let text = match input::read(&args.filename) {
+        Ok(text) => text,
+        Err(e) => fail(format!("Error reading from {}: {}", name, e))
+    };
+    let mut report = Report::new(day);
+    let start = Instant::now();
+    if let Err(e) = solve(&text, &args, &mut report) {
+        fail(render(e.as_ref(), name, &text));
+    }
+    finish(report, &args, start);
+}
+
+/// As `run`, but hands `solve` a reader over the input rather than reading it all up front, for days
+/// which can work through input too large to hold. Diagnostics point at the line of the input without
+/// quoting it, since it's no longer there to quote.
+pub fn run_streaming<F>(day: usize, extra_names: &[&'static str], option_names: &[&'static str], solve: F) where F: FnOnce(&mut dyn BufRead, &Args, &mut Report) -> Result<(), Box<dyn Diagnostic>> {
+    let args = parse_args(extra_names, option_names);
+    let name = input::name(&args.filename);
+    let mut reader = match input::open(&args.filename) {
+        Ok(reader) => reader,
+        Err(e) => fail(format!("Error reading from {}: {}", name, e))
+    };
+    let mut report = Report::new(day);
+    let start = Instant::now();
+    if let Err(e) = solve(&mut reader, &args, &mut report) {
+        fail(render(e.as_ref(), name, ""));
+    }
+    finish(report, &args, start);
+}
+
+fn parse_args(extra_names: &[&'static str], option_names: &[&'static str]) -> Args {
+    match Args::new(env::args().skip(1).collect(), extra_names, option_names) {
+        Ok(args) => args,
+        Err(usage) => {
+            println!("{}", usage);
+            process::exit(2);
+        }
+    }
+}
+
+fn finish(mut report: Report, args: &Args, start: Instant) {
+    report.time("total", start.elapsed());
+    match args.format {
+        Format::Text => println!("{}", report),
+        Format::Json => println!("{}", report.to_json())
+    }
+    if !report.errors.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A small, fast pseudo-random number generator (xorshift64*), seeded so that generated puzzle input
+/// is reproducible. Not suitable for anything needing real randomness.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 spreads out nearby seeds, and never leaves xorshift with its stuck all-zero state
+        let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        Self {
+            state: (z ^ (z >> 31)).max(1)
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    /// A number from 0 up to but not including `n`, which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't choose a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number from `min` to `max` inclusive.
+    pub fn between(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "empty range {}..={}", min, max);
+        min + ((self.next_u64() as u128 * (max.abs_diff(min) as u128 + 1)) >> 64) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Checks that displaying a value then parsing the text gives back the same value, for values made
+/// from each seed up to `cases`. Panics with the seed and text of the first value which doesn't.
+pub fn assert_round_trip<T, E>(cases: u64, mut generate: impl FnMut(u64) -> T) where T: Display + FromStr<Err = E> + PartialEq, E: Display {
+    for seed in 0..cases {
+        let value = generate(seed);
+        let text = value.to_string();
+        match text.parse::<T>() {
+            Ok(parsed) => if parsed != value {
+                panic!("seed {}: parsing\n{}\ngave a different value, displayed as\n{}", seed, text, parsed);
+            },
+            Err(e) => panic!("seed {}: failed to parse\n{}\n{}", seed, text, e)
+        }
+    }
+}
\ No newline at end of file
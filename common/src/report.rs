@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use crate::Answer;
+
+/// How a binary prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {}", s))
+        }
+    }
+}
+
+/// What solving a part gives: an answer, or for parts which can fail on some input, a result.
+pub trait Solution {
+    fn into_result(self) -> Result<Answer, String>;
+}
+
+impl Solution for Answer {
+    fn into_result(self) -> Result<Answer, String> {
+        Ok(self)
+    }
+}
+
+impl<E: Display> Solution for Result<Answer, E> {
+    fn into_result(self) -> Result<Answer, String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// The results of solving one day's puzzle: the answer to each part, any intermediate values worth
+/// reporting along the way, and how long each step took.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: usize,
+    pub answers: Vec<(usize, Answer)>,
+    pub errors: Vec<(usize, String)>,
+    pub values: Vec<(String, Answer)>,
+    pub timing: Vec<(String, Duration)>
+}
+
+impl Report {
+    pub fn new(day: usize) -> Self {
+        Self {
+            day,
+            answers: Vec::new(),
+            errors: Vec::new(),
+            values: Vec::new(),
+            timing: Vec::new()
+        }
+    }
+
+    /// Parses the input, recording how long it took.
+    pub fn parse<T, E>(&mut self, parse: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+        let start = Instant::now();
+        let result = parse();
+        self.time("parse", start.elapsed());
+        result
+    }
+
+    /// Solves a part, recording its answer, or the error if it failed, and how long it took.
+    pub fn part<S: Solution>(&mut self, part: usize, solve: impl FnOnce() -> S) {
+        let start = Instant::now();
+        match solve().into_result() {
+            Ok(answer) => self.answer(part, answer, start.elapsed()),
+            Err(message) => self.error(part, message, start.elapsed())
+        }
+    }
+
+    pub fn answer(&mut self, part: usize, answer: Answer, elapsed: Duration) {
+        self.answers.push((part, answer));
+        self.time(&format!("part{}", part), elapsed);
+    }
+
+    pub fn error(&mut self, part: usize, message: String, elapsed: Duration) {
+        self.errors.push((part, message));
+        self.time(&format!("part{}", part), elapsed);
+    }
+
+    /// Records an intermediate value, named in snake case.
+    pub fn value(&mut self, name: &str, value: impl Into<Answer>) {
+        self.values.push((name.to_string(), value.into()));
+    }
+
+    pub fn time(&mut self, name: &str, elapsed: Duration) {
+        self.timing.push((name.to_string(), elapsed));
+    }
+
+    /// The report as a single line JSON object, with timings in microseconds:
+    ///
+    /// ```text
+    /// {"day":4,"answers":{"part1":4512},"values":{"first_winning_board":3},"timing_us":{"parse":52,"part1":8}}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut fields = vec![("day".to_string(), self.day.to_string())];
+        fields.push(("answers".to_string(), json_object(self.answers.iter().map(|(p, a)| (format!("part{}", p), json_answer(a))))));
+        if !self.errors.is_empty() {
+            fields.push(("errors".to_string(), json_object(self.errors.iter().map(|(p, e)| (format!("part{}", p), json_string(e))))));
+        }
+        fields.push(("values".to_string(), json_object(self.values.iter().map(|(n, v)| (n.clone(), json_answer(v))))));
+        fields.push(("timing_us".to_string(), json_object(self.timing.iter().map(|(n, t)| (n.clone(), t.as_micros().to_string())))));
+        json_object(fields.into_iter())
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        let elapsed = |name: &str| self.timing.iter().find(|(n, _)| n == name).map(|(_, t)| *t).unwrap_or_default();
+        for (part, answer) in &self.answers {
+            writeln!(f, "Part {}: {} ({:?})", part, answer, elapsed(&format!("part{}", part)))?;
+        }
+        for (part, error) in &self.errors {
+            writeln!(f, "Part {}: FAILED: {}", part, error)?;
+        }
+        for (name, value) in &self.values {
+            let label = name.replace('_', " ");
+            let mut chars = label.chars();
+            let label = chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or(label);
+            writeln!(f, "{}: {}", label, value)?;
+        }
+        write!(f, "Total time: {:?}", elapsed("total"))?;
+        Ok(())
+    }
+}
+
+fn json_object(fields: impl Iterator<Item = (String, String)>) -> String {
+    let fields: Vec<String> = fields.map(|(name, value)| format!("{}:{}", json_string(&name), value)).collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(t) => json_string(t)
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if (c as u32) < 0x20 => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c)
+        }
+    }
+    json.push('"');
+    json
+}
\ No newline at end of file
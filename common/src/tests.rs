@@ -0,0 +1,232 @@
+use super::*;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug)]
+struct TestError(Span);
+
+impl Display for TestError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "expected a digit, found `{}`", self.0.text)
+    }
+}
+
+impl Diagnostic for TestError {
+    fn span(&self) -> Option<&Span> {
+        Some(&self.0)
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        Some(&mut self.0)
+    }
+}
+
+fn strings(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn args_filename_only() {
+    let args = Args::new(strings(&["input.txt"]), &[], &[]).unwrap();
+    assert_eq!(args.filename, "input.txt");
+}
+
+#[test]
+fn args_extra_named() {
+    let args = Args::new(strings(&["input.txt", "100"]), &["Rounds"], &[]).unwrap();
+    assert_eq!(args.get::<usize>("Rounds"), Ok(100));
+    assert!(args.get::<usize>("Steps").is_err());
+}
+
+#[test]
+fn args_invalid_extra() {
+    let args = Args::new(strings(&["input.txt", "lots"]), &["Rounds"], &[]).unwrap();
+    assert_eq!(args.get::<usize>("Rounds"), Err("Invalid Rounds: lots".to_string()));
+}
+
+#[test]
+fn args_stdin() {
+    assert_eq!(Args::new(strings(&[]), &[], &[]).unwrap().filename, "-");
+    assert_eq!(Args::new(strings(&["-"]), &[], &[]).unwrap().filename, "-");
+    let args = Args::new(strings(&["100", "--format", "json"]), &["Rounds"], &[]).unwrap();
+    assert_eq!(args.filename, "-");
+    assert_eq!(args.get::<usize>("Rounds"), Ok(100));
+}
+
+#[test]
+fn args_usage() {
+    assert_eq!(Args::new(strings(&["a.txt", "b.txt"]), &[], &[]).err(), Some("Usage: [Filename] [--format text|json]\nWithout a filename, or with -, input is read from stdin".to_string()));
+    assert_eq!(Args::new(strings(&[]), &["Steps"], &[]).err(), Some("Usage: [Filename] Steps [--format text|json]\nWithout a filename, or with -, input is read from stdin".to_string()));
+}
+
+#[test]
+fn args_format() {
+    assert_eq!(Args::new(strings(&["input.txt"]), &[], &[]).unwrap().format, Format::Text);
+    let args = Args::new(strings(&["input.txt", "--format", "json", "100"]), &["Rounds"], &[]).unwrap();
+    assert_eq!(args.format, Format::Json);
+    assert_eq!(args.get::<usize>("Rounds"), Ok(100));
+    assert_eq!(Args::new(strings(&["--format", "xml", "input.txt"]), &[], &[]).err(), Some("Invalid format: xml".to_string()));
+}
+
+#[test]
+fn args_options() {
+    let args = Args::new(strings(&["--strict", "input.txt", "--max-depth=100", "all"]), &["Models"], &["strict", "max-depth", "min-depth"]).unwrap();
+    assert_eq!(args.filename, "input.txt");
+    assert!(args.flag("strict"));
+    assert!(!args.flag("verbose"));
+    assert_eq!(args.option::<i64>("max-depth"), Ok(Some(100)));
+    assert_eq!(args.option::<i64>("min-depth"), Ok(None));
+    assert!(args.option::<i64>("strict").is_err());
+}
+
+#[test]
+fn args_unknown_option() {
+    assert_eq!(Args::new(strings(&["input.txt", "--stict"]), &[], &["strict", "max-depth"]).err(),
+        Some("Unknown option: --stict\nUsage: [Filename] [--format text|json] [--strict] [--max-depth]\nWithout a filename, or with -, input is read from stdin".to_string()));
+    assert!(Args::new(strings(&["input.txt", "--window=2,5"]), &[], &["windows"]).is_err());
+    assert!(Args::new(strings(&["input.txt", "--strict"]), &[], &[]).is_err());
+}
+
+#[test]
+fn report_json() {
+    let mut report = Report::new(4);
+    report.answer(1, 4512.into(), Duration::from_micros(8));
+    report.answer(2, "a\"b\n".into(), Duration::from_micros(9));
+    report.value("first_winning_board", 3);
+    assert_eq!(report.to_json(), r#"{"day":4,"answers":{"part1":4512,"part2":"a\"b\n"},"values":{"first_winning_board":3},"timing_us":{"part1":8,"part2":9}}"#);
+}
+
+#[test]
+fn input_lines_any_ending() {
+    let unix: Vec<&str> = input::lines("199\n200 \n208\n\n").collect();
+    let windows: Vec<&str> = input::lines("199\r\n200 \r\n208\r\n").collect();
+    assert_eq!(unix, vec!["199", "200", "208"]);
+    assert_eq!(windows, unix);
+}
+
+#[test]
+fn input_sections_any_ending() {
+    assert_eq!(input::sections("1,2\n\n a\nb\n\n\nc\n"), vec!["1,2", " a\nb", "c"]);
+    assert_eq!(input::sections("1,2\r\n\r\n a\r\nb\r\n"), vec!["1,2", " a\r\nb"]);
+    assert!(input::sections("\n \n").is_empty());
+}
+
+#[test]
+fn input_parse_lines_numbers_errors() {
+    #[derive(Debug)]
+    struct Digit;
+    impl FromStr for Digit {
+        type Err = TestError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            match line.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => Err(TestError(Span::char_at(line, i))),
+                None => Ok(Digit)
+            }
+        }
+    }
+    let error = input::parse_lines::<Digit, TestError>("12\r\n34\r\n5x6\r\n").unwrap_err();
+    assert_eq!(error.0, Span { line: 3, column: 2, text: "x".to_string() });
+}
+
+#[test]
+fn input_validate_lines_lists_every_problem() {
+    use crate::input::Validate;
+    struct Digits;
+    impl FromStr for Digits {
+        type Err = TestError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            Digits::problems(line).into_iter().next().map_or(Ok(Digits), Err)
+        }
+    }
+    impl input::Validate for Digits {
+        fn problems(line: &str) -> Vec<TestError> {
+            line.char_indices().filter(|(_, c)| !c.is_ascii_digit()).map(|(i, _)| TestError(Span::char_at(line, i))).collect()
+        }
+    }
+    let errors = input::validate_lines::<Digits, TestError>("12\nx4y\n5\nz");
+    let spans: Vec<(usize, usize)> = errors.iter().map(|e| (e.0.line, e.0.column)).collect();
+    assert_eq!(spans, vec![(2, 1), (2, 3), (4, 1)]);
+}
+
+#[test]
+fn input_numbered_sections() {
+    assert_eq!(input::numbered_sections("a\n\n\nb\nc\n\nd"), vec![(0, "a"), (3, "b\nc"), (6, "d")]);
+}
+
+#[test]
+fn span_of_token() {
+    let line = "0,9 -> x,9";
+    let token = line.split(" -> ").nth(1).unwrap().split(",").next().unwrap();
+    assert_eq!(Span::new(line, token), Span { line: 1, column: 8, text: "x".to_string() });
+    assert_eq!(Span::new(line, "x").column, 1);
+}
+
+#[test]
+fn render_diagnostic() {
+    let error = TestError(Span { line: 2, column: 8, text: "x".to_string() });
+    assert_eq!(render(&error, "input.txt", "0,9 -> 5,9\r\n0,9 -> x,9\r\n"), "\
+error: expected a digit, found `x`
+ --> input.txt:2:8
+  |
+2 | 0,9 -> x,9
+  |        ^");
+    assert_eq!(render(&"No solution".to_string(), "input.txt", ""), "error: No solution");
+}
+
+#[test]
+fn render_diagnostic_in_other_file() {
+    let error = FileError::new(TestError(Span { line: 1, column: 2, text: "x".to_string() }), "boards.txt", "1x".to_string());
+    assert_eq!(render(&error, "input.txt", "0,9 -> 5,9"), "\
+error: expected a digit, found `x`
+ --> boards.txt:1:2
+  |
+1 | 1x
+  |  ^");
+}
+
+#[test]
+fn grid_parse_and_display() {
+    let grid = Grid::parse("219\r\n398\r\n", |c| c.to_digit(10)).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Position::new(2, 1)], 8);
+    assert_eq!(grid.get(Position::new(3, 0)), None);
+    assert_eq!(grid.to_string(), "219\n398\n");
+    assert_eq!(Grid::parse(&grid.to_string(), |c| c.to_digit(10)), Ok(grid));
+}
+
+#[test]
+fn grid_parse_errors() {
+    assert_eq!(Grid::parse("21\n3x", |c| c.to_digit(10)), Err(GridError::InvalidCell(Span { line: 2, column: 2, text: "x".to_string() })));
+    assert_eq!(Grid::parse("21\n398", |c| c.to_digit(10)), Err(GridError::InvalidRowLength { span: Span { line: 2, column: 1, text: "398".to_string() }, expected: 2 }));
+    assert_eq!(Grid::parse("\n", |c| c.to_digit(10)), Err(GridError::Empty));
+}
+
+#[test]
+fn grid_neighbours() {
+    let grid = Grid::from_fn(3, 3, |p| p);
+    let corner = Position::new(0, 0);
+    assert_eq!(grid.neighbours4(corner).collect::<Vec<_>>(), vec![Position::new(1, 0), Position::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+    let grid = grid.wrapping(true);
+    assert_eq!(grid.neighbours4(corner).collect::<Vec<_>>(), vec![Position::new(0, 2), Position::new(2, 0), Position::new(1, 0), Position::new(0, 1)]);
+    assert_eq!(grid.offset(corner, -1, -1), Some(Position::new(2, 2)));
+}
+
+#[test]
+fn rng_is_reproducible() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let values: Vec<i64> = (0..100).map(|_| a.between(-3, 3)).collect();
+    assert_eq!(values, (0..100).map(|_| b.between(-3, 3)).collect::<Vec<_>>());
+    assert!(values.iter().all(|v| (-3..=3).contains(v)));
+    assert!((-3..=3).all(|v| values.contains(&v)));
+    assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    let mut items: Vec<usize> = (0..10).collect();
+    a.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, (0..10).collect::<Vec<_>>());
+}
\ No newline at end of file
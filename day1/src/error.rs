@@ -0,0 +1,39 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A depth which isn't a whole number
+    InvalidDepth(Span),
+    /// A window size of 0, which can't hold any depths
+    EmptyWindow,
+    /// Input which couldn't be read while streaming it
+    Unreadable(String)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidDepth(span) => write!(f, "expected a depth, found `{}`", span.text),
+            Error::EmptyWindow => write!(f, "expected windows to hold at least one depth"),
+            Error::Unreadable(message) => write!(f, "couldn't read input: {}", message)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidDepth(span) => Some(span),
+            Error::EmptyWindow | Error::Unreadable(_) => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidDepth(span) => Some(span),
+            Error::EmptyWindow | Error::Unreadable(_) => None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 2000;
+
+/// A sonar sweep of `size` depths, drifting deeper with some noise like the real report.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut depth = rng.between(100, 200);
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        lines.push(depth.to_string());
+        depth = (depth + rng.between(-10, 20)).max(0);
+    }
+    lines.join("\n")
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+
+pub mod generate;
+mod error;
+mod profile;
+
+pub use error::Error;
+pub use profile::{Histogram, Plateau, Profile, Run, profile};
+
+#[cfg(test)]
+mod tests;
+
+pub type Input = Vec<u32>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    depths(text).collect()
+}
+
+/// Checks every line of the input, listing each which isn't a depth.
+pub fn validate(text: &str) -> Vec<Error> {
+    depths(text).filter_map(Result::err).collect()
+}
+
+/// The window size for part 2, which is checked to be positive when compiling.
+const PART2_WINDOW: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+pub fn part1(depths: &Input) -> Answer {
+    increasing(depths, NonZeroUsize::MIN).into()
+}
+
+pub fn part2(depths: &Input) -> Answer {
+    increasing(depths, PART2_WINDOW).into()
+}
+
+/// How many windows had a larger, smaller or equal sum to the window before.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Changes {
+    pub increased: usize,
+    pub decreased: usize,
+    pub unchanged: usize
+}
+
+/// Compares each sliding window of a stream of depths with the one before, holding only the depths in
+/// the current window.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    size: usize,
+    window: VecDeque<u32>,
+    sum: u64,
+    previous: Option<u64>,
+    changes: Changes
+}
+
+/// Window sizes given on the command line as a comma separated list, such as `1,3,10`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowSizes(pub Vec<usize>);
+
+/// Parses depths one line at a time, so they can be swept without collecting them first.
+pub fn depths(text: &str) -> impl Iterator<Item = Result<u32, Error>> + '_ {
+    input::lines(text).enumerate().map(|(i, s)| s.parse().map_err(|_| Error::InvalidDepth(Span::line(s).on_line(i + 1))))
+}
+
+/// Reads depths a line at a time from a reader, such as a sensor log too large to hold. As with `depths`,
+/// blank lines at the end are skipped, so one in the middle is only reported once a depth follows it.
+pub fn read_depths(reader: impl BufRead) -> impl Iterator<Item = Result<u32, Error>> {
+    let mut blanks = 0;
+    reader.lines().enumerate().flat_map(move |(i, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return vec![Err(Error::Unreadable(e.to_string()))]
+        };
+        let line = line.trim_end();
+        if line.is_empty() {
+            blanks += 1;
+            return Vec::new();
+        }
+        let mut depths: Vec<Result<u32, Error>> = (i - blanks..i).map(|b| Err(Error::InvalidDepth(Span::line("").on_line(b + 1)))).collect();
+        blanks = 0;
+        depths.push(line.parse().map_err(|_| Error::InvalidDepth(Span::line(line).on_line(i + 1))));
+        depths
+    })
+}
+
+/// Sweeps a stream of depths once with several window sizes, counting the changes for each.
+pub fn analyse(depths: impl IntoIterator<Item = u32>, sizes: &[usize]) -> Result<Vec<Changes>, Error> {
+    try_analyse(depths.into_iter().map(Ok), sizes).map(|(_, changes)| changes)
+}
+
+/// As `analyse`, for depths still to be parsed, stopping at the first which isn't a depth. Also gives how
+/// many depths were swept.
+pub fn try_analyse(depths: impl IntoIterator<Item = Result<u32, Error>>, sizes: &[usize]) -> Result<(usize, Vec<Changes>), Error> {
+    let mut sweeps: Vec<Sweep> = sizes.iter().map(|&size| Sweep::new(size)).collect::<Result<_, _>>()?;
+    let mut count = 0;
+    for depth in depths {
+        let depth = depth?;
+        count += 1;
+        for sweep in &mut sweeps {
+            sweep.push(depth);
+        }
+    }
+    Ok((count, sweeps.iter().map(|s| s.changes()).collect()))
+}
+
+/// The number of windows of `size` depths with a larger sum than the window before.
+pub fn count_increasing(depths: &[u32], size: usize) -> Result<usize, Error> {
+    let size = NonZeroUsize::new(size).ok_or(Error::EmptyWindow)?;
+    Ok(increasing(depths, size))
+}
+
+fn increasing(depths: &[u32], size: NonZeroUsize) -> usize {
+    let mut sweep = Sweep::with_size(size);
+    for &depth in depths {
+        sweep.push(depth);
+    }
+    sweep.changes().increased
+}
+
+impl Sweep {
+    /// A sweep of windows of `size` depths, which must hold at least one.
+    pub fn new(size: usize) -> Result<Self, Error> {
+        NonZeroUsize::new(size).map(Self::with_size).ok_or(Error::EmptyWindow)
+    }
+
+    pub fn with_size(size: NonZeroUsize) -> Self {
+        let size = size.get();
+        Self {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            sum: 0,
+            previous: None,
+            changes: Changes::default()
+        }
+    }
+
+    /// Adds the next depth, giving the sum of the window it completes, if it fills one.
+    pub fn push(&mut self, depth: u32) -> Option<u64> {
+        self.window.push_back(depth);
+        self.sum += depth as u64;
+        if self.window.len() > self.size {
+            self.sum -= self.window.pop_front().unwrap() as u64;
+        }
+        if self.window.len() == self.size {
+            if let Some(previous) = self.previous {
+                match self.sum.cmp(&previous) {
+                    Ordering::Greater => self.changes.increased += 1,
+                    Ordering::Less => self.changes.decreased += 1,
+                    Ordering::Equal => self.changes.unchanged += 1
+                }
+            }
+            self.previous = Some(self.sum);
+            return Some(self.sum);
+        }
+        None
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn changes(&self) -> Changes {
+        self.changes
+    }
+}
+
+impl FromStr for WindowSizes {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.split(",").map(|n| n.trim().parse().ok().filter(|&n| n > 0).ok_or(format!("Invalid window size: {}", n)))
+            .collect::<Result<_, _>>().map(WindowSizes)
+    }
+}
\ No newline at end of file
@@ -1,33 +1,55 @@
-use std::env;
-use std::fs;
+use aoc_common::Answer;
+use day1::{part1, part2, profile, read_depths, try_analyse, WindowSizes};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let numbers: Vec<u32> = text.split("\r\n").map(|s| s.parse()
-            .expect(&format!("Error parsing number {}", s))).collect();
-        println!("Increasing by 1: {}", count_increasing(&numbers, 1));
-        println!("Increasing by 3: {}", count_increasing(&numbers, 3));
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
-}
-
-fn count_increasing(list: &Vec<u32>, by: usize) -> u32 {
-    let mut count: u32 = 0;
-    let mut previous: u32 = 0;
-    for i in by-1..list.len() {
-        let mut value: u32 = 0;
-        for offset in 0..by {
-            value += list[i-offset];
-        }
-        if value > previous {
-            count += 1;
+    aoc_common::run_streaming(1, &[], &["windows", "profile"], |reader, args, report| {
+        let WindowSizes(sizes) = args.option("windows")?.unwrap_or(WindowSizes(vec![1, 3]));
+        let profile_size: Option<usize> = args.option("profile")?;
+        match profile_size {
+            // Parts 1 and 2 are windows of 1 and 3, so every window is counted in a single pass over the input
+            None => {
+                let all: Vec<usize> = [1, 3].into_iter().chain(sizes.iter().copied()).collect();
+                let (depths, changes) = report.parse(|| try_analyse(read_depths(reader), &all))?;
+                report.part(1, || Answer::from(changes[0].increased));
+                report.part(2, || Answer::from(changes[1].increased));
+                report.value("depths", depths);
+                for (size, changes) in sizes.iter().zip(&changes[2..]) {
+                    report.value(&format!("window_{}_increased", size), changes.increased);
+                    report.value(&format!("window_{}_decreased", size), changes.decreased);
+                    report.value(&format!("window_{}_unchanged", size), changes.unchanged);
+                }
+            },
+            Some(size) => {
+                let input = report.parse(|| read_depths(reader).collect::<Result<Vec<u32>, _>>())?;
+                report.part(1, || part1(&input));
+                report.part(2, || part2(&input));
+                report.value("depths", input.len());
+                let profile = profile(&input, size)?;
+                report.value("window_size", size);
+                report.value("windows", profile.windows);
+                if let (Some(min), Some(max), Some(mean)) = (profile.min, profile.max, profile.mean) {
+                    report.value("min_sum", min);
+                    report.value("max_sum", max);
+                    report.value("mean_sum", format!("{:.2}", mean));
+                }
+                let longest_plateau = profile.plateaus.iter().rev().max_by_key(|p| p.run.length).map(|p| p.run);
+                let runs = [("longest_increasing", profile.longest_increasing), ("longest_decreasing", profile.longest_decreasing), ("longest_plateau", longest_plateau)];
+                for (name, run) in runs {
+                    if let Some(run) = run {
+                        report.value(&format!("{}_start", name), run.start);
+                        report.value(&format!("{}_length", name), run.length);
+                    }
+                }
+                report.value("plateaus", profile.plateaus.len());
+                if !profile.plateaus.is_empty() {
+                    let plateaus: Vec<String> = profile.plateaus.iter()
+                        .map(|p| format!("start {}, length {}, sum {}", p.run.start, p.run.length, p.sum))
+                        .collect();
+                    report.value("detected_plateaus", format!("\n{}", plateaus.join("\n")));
+                }
+                report.value("deltas", format!("\n{}", profile.deltas));
+            }
         }
-        previous = value;
-    }
-    return count - 1;
+        Ok(())
+    });
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use crate::{Changes, Error, Sweep};
+
+/// At most this many buckets are drawn for the histogram of deltas.
+const MAX_BUCKETS: i64 = 20;
+
+/// A stretch of consecutive windows, from window `start`, counting from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize
+}
+
+/// A run of at least 2 windows which all have the same sum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plateau {
+    pub run: Run,
+    pub sum: u64
+}
+
+/// How often the window sum changed by each amount, grouped into buckets of equal `width`. Each
+/// bucket is keyed by the smallest delta it holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub width: i64,
+    pub buckets: BTreeMap<i64, usize>
+}
+
+/// Statistics on the sums of every window of `size` depths.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub size: usize,
+    pub windows: usize,
+    pub changes: Changes,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub mean: Option<f64>,
+    pub longest_increasing: Option<Run>,
+    pub longest_decreasing: Option<Run>,
+    pub plateaus: Vec<Plateau>,
+    pub deltas: Histogram
+}
+
+/// Profiles the window sums of a list of depths. Runs count windows, so a strictly increasing run of
+/// 3 windows has 2 increases, and plateaus are runs of at least 2 windows with the same sum.
+pub fn profile(depths: &[u32], size: usize) -> Result<Profile, Error> {
+    let mut sweep = Sweep::new(size)?;
+    let sums: Vec<u64> = depths.iter().filter_map(|&d| sweep.push(d)).collect();
+    let deltas: Vec<i64> = sums.windows(2).map(|w| w[1] as i64 - w[0] as i64).collect();
+    let plateaus = runs(&deltas, |d| d == 0).into_iter().filter(|r| r.length > 1).map(|run| Plateau { run, sum: sums[run.start] }).collect();
+    Ok(Profile {
+        size,
+        windows: sums.len(),
+        changes: sweep.changes(),
+        min: sums.iter().min().copied(),
+        max: sums.iter().max().copied(),
+        mean: (!sums.is_empty()).then(|| sums.iter().sum::<u64>() as f64 / sums.len() as f64),
+        longest_increasing: longest(runs(&deltas, |d| d > 0), sums.len()),
+        longest_decreasing: longest(runs(&deltas, |d| d < 0), sums.len()),
+        plateaus,
+        deltas: Histogram::new(&deltas)
+    })
+}
+
+/// Every maximal run of windows joined by deltas matching `joined`, including lone windows.
+fn runs(deltas: &[i64], joined: impl Fn(i64) -> bool) -> Vec<Run> {
+    let mut runs = vec![Run { start: 0, length: 1 }];
+    for (i, &delta) in deltas.iter().enumerate() {
+        let run = runs.last_mut().unwrap();
+        if joined(delta) {
+            run.length += 1;
+        } else {
+            runs.push(Run { start: i + 1, length: 1 });
+        }
+    }
+    runs
+}
+
+/// The first of the longest runs, if there are any windows at all.
+fn longest(runs: Vec<Run>, windows: usize) -> Option<Run> {
+    runs.into_iter().filter(|_| windows > 0).rev().max_by_key(|r| r.length)
+}
+
+impl Histogram {
+    /// Picks the narrowest of 1, 2, 5, 10, 20, 50 and so on which needs no more than `MAX_BUCKETS` buckets.
+    pub fn new(deltas: &[i64]) -> Self {
+        let (min, max) = (deltas.iter().min().copied().unwrap_or(0), deltas.iter().max().copied().unwrap_or(0));
+        let width = [1, 2, 5].iter().cycle().scan(1, |scale, &step| {
+            let width = step * *scale;
+            if step == 5 {
+                *scale *= 10;
+            }
+            Some(width)
+        }).find(|&width| bucket(max, width) - bucket(min, width) < MAX_BUCKETS * width).unwrap();
+        let mut buckets = BTreeMap::new();
+        for &delta in deltas {
+            *buckets.entry(bucket(delta, width)).or_insert(0) += 1;
+        }
+        Self { width, buckets }
+    }
+}
+
+fn bucket(delta: i64, width: i64) -> i64 {
+    delta.div_euclid(width) * width
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{} windows from window {}", self.length, self.start)
+    }
+}
+
+impl Display for Plateau {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{}, summing to {}", self.run, self.sum)
+    }
+}
+
+impl Display for Histogram {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        let labels: Vec<String> = self.buckets.keys().map(|&start| match self.width {
+            1 => start.to_string(),
+            width => format!("{}..={}", start, start + width - 1)
+        }).collect();
+        let width = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        for (i, (label, count)) in labels.iter().zip(self.buckets.values()).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:>width$} {}", label, count, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        let or_none = |run: Option<Run>| run.map(|r| r.to_string()).unwrap_or("none".to_string());
+        writeln!(f, "Windows of {}: {}", self.size, self.windows)?;
+        writeln!(f, "Increased: {}, decreased: {}, unchanged: {}", self.changes.increased, self.changes.decreased, self.changes.unchanged)?;
+        if let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean) {
+            writeln!(f, "Sum: min {}, max {}, mean {:.2}", min, max, mean)?;
+        }
+        writeln!(f, "Longest increasing: {}", or_none(self.longest_increasing))?;
+        writeln!(f, "Longest decreasing: {}", or_none(self.longest_decreasing))?;
+        writeln!(f, "Plateaus: {}", self.plateaus.len())?;
+        for plateau in &self.plateaus {
+            writeln!(f, "  {}", plateau)?;
+        }
+        write!(f, "Deltas:")?;
+        if !self.deltas.buckets.is_empty() {
+            write!(f, "\n{}", self.deltas)?;
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
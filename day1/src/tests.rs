@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn first_window_of_zero_counts_next_increase() {
+    assert_eq!(count_increasing(&[0, 0, 0, 1], 3), Ok(1));
+    assert_eq!(count_increasing(&[0, 1], 1), Ok(1));
+}
+
+#[test]
+fn analyse_counts_each_window_size() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    let changes = analyse(depths, &[1, 3, 10, 11]).unwrap();
+    assert_eq!(changes[0], Changes { increased: 7, decreased: 2, unchanged: 0 });
+    assert_eq!(changes[1], Changes { increased: 5, decreased: 1, unchanged: 1 });
+    assert_eq!(changes[2], Changes::default());
+    assert_eq!(changes[3], Changes::default());
+}
+
+#[test]
+fn window_sizes_must_be_positive() {
+    assert_eq!("1, 3,10".parse(), Ok(WindowSizes(vec![1, 3, 10])));
+    assert!("1,0".parse::<WindowSizes>().is_err());
+    assert!("".parse::<WindowSizes>().is_err());
+    assert_eq!(Sweep::new(0).err(), Some(Error::EmptyWindow));
+    assert_eq!(analyse([1, 2], &[1, 0]), Err(Error::EmptyWindow));
+}
+
+#[test]
+fn profile_finds_runs_and_plateaus() {
+    let profile = profile(&[1, 2, 3, 3, 3, 2, 1, 0, 5], 1).unwrap();
+    assert_eq!(profile.longest_increasing, Some(Run { start: 0, length: 3 }));
+    assert_eq!(profile.longest_decreasing, Some(Run { start: 4, length: 4 }));
+    assert_eq!(profile.plateaus, vec![Plateau { run: Run { start: 2, length: 3 }, sum: 3 }]);
+    assert_eq!(super::profile(&[1, 2, 1, 2, 1, 4], 2).unwrap().plateaus, vec![Plateau { run: Run { start: 0, length: 4 }, sum: 3 }]);
+    assert_eq!((profile.min, profile.max), (Some(0), Some(5)));
+    assert_eq!(profile.deltas.width, 1);
+    assert_eq!(profile.deltas.buckets.get(&-1), Some(&3));
+    assert_eq!(super::profile(&[1, 2], 3).unwrap().longest_increasing, None);
+}
+
+#[test]
+fn profile_labels() {
+    let plateau = Plateau { run: Run { start: 2, length: 3 }, sum: 9 };
+    assert_eq!(plateau.to_string(), "3 windows from window 2, summing to 9");
+    let histogram = Histogram { width: 5, buckets: [(-5, 1), (0, 2)].into_iter().collect() };
+    assert_eq!(histogram.to_string(), "-5..=-1 1\n  0..=4 2");
+}
+
+#[test]
+fn read_depths_matches_depths() {
+    let text = "199\r\n200\n\n208\nx\n210\n\n\n";
+    let read: Vec<Result<u32, Error>> = read_depths(text.as_bytes()).collect();
+    assert_eq!(read, depths(text).collect::<Vec<_>>());
+    assert_eq!(read[2], Err(Error::InvalidDepth(Span::line("").on_line(3))));
+    assert_eq!(try_analyse(read_depths("1\n2\n3\n2".as_bytes()), &[1, 2]), Ok((4, vec![Changes { increased: 2, decreased: 1, unchanged: 0 }, Changes { increased: 1, decreased: 0, unchanged: 1 }])));
+    assert!(try_analyse(read_depths("1\nx\n3".as_bytes()), &[1]).is_err());
+}
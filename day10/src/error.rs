@@ -0,0 +1,36 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A character which isn't a bracket
+    InvalidChar(Span),
+    /// Input without any incomplete lines to score
+    NoIncompleteLines
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidChar(span) => write!(f, "expected one of `()[]{{}}<>`, found `{}`", span.text),
+            Error::NoIncompleteLines => write!(f, "expected at least one incomplete line")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidChar(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidChar(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+const OPEN: [char; 4] = ['(', '[', '{', '<'];
+const CLOSE: [char; 4] = [')', ']', '}', '>'];
+
+/// The deepest a line nests, keeping autocomplete scores within a `u64`.
+const MAX_DEPTH: usize = 16;
+
+/// `size` lines of navigation subsystem, roughly half corrupted and the rest incomplete, always starting
+/// with an incomplete line.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    for i in 0..size {
+        let corrupted = i > 0 && rng.chance(0.5);
+        let length = rng.between(20, 110) as usize;
+        let mut line = String::new();
+        let mut open = Vec::new();
+        while line.len() < length || open.is_empty() {
+            if open.is_empty() || (open.len() < MAX_DEPTH && rng.chance(0.55)) {
+                let bracket = rng.below(4);
+                open.push(bracket);
+                line.push(OPEN[bracket]);
+            } else {
+                line.push(CLOSE[open.pop().unwrap()]);
+            }
+        }
+        if corrupted {
+            let expected = *open.last().unwrap();
+            let wrong = (expected + 1 + rng.below(3)) % 4;
+            line.push(CLOSE[wrong]);
+            for _ in 0..rng.below(10) {
+                line.push(*rng.choose(&[OPEN, CLOSE].concat()));
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
\ No newline at end of file
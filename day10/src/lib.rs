@@ -0,0 +1,138 @@
+use aoc_common::{Answer, Diagnostic, Span};
+use aoc_common::input;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Bracket {
+    Round,
+    Square,
+    Brace,
+    Arrow
+}
+
+pub enum ParseResult {
+    Valid,
+    Incomplete { open: Vec<Bracket> },
+    Corrupted { found: Bracket, expected: Option<Bracket> }
+}
+
+/// The result of parsing each line of the navigation subsystem.
+pub type Input = Vec<ParseResult>;
+
+/// Parses every line, checking that at least one is incomplete.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let results: Vec<ParseResult> = input::lines(text).enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.offset_lines(i))).collect::<Result<_, _>>()?;
+    if !results.iter().any(|r| matches!(r, ParseResult::Incomplete { .. })) {
+        return Err(Error::NoIncompleteLines);
+    }
+    Ok(results)
+}
+
+/// Checks every line of the input, listing each character which isn't a bracket, and that some line
+/// is incomplete.
+pub fn validate(text: &str) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let mut incomplete = false;
+    for (i, line) in input::lines(text).enumerate() {
+        for (c, bracket) in line.chars().enumerate() {
+            if Bracket::open(bracket).is_none() && Bracket::close(bracket).is_none() {
+                errors.push(Error::InvalidChar(Span::char_at(line, c).on_line(i + 1)));
+            }
+        }
+        incomplete |= matches!(parse_line(line), Ok(ParseResult::Incomplete { .. }));
+    }
+    if !incomplete {
+        errors.push(Error::NoIncompleteLines);
+    }
+    errors
+}
+
+pub fn part1(results: &Input) -> Answer {
+    results.iter().map(|r| match r {
+        ParseResult::Corrupted { found, .. } => found.syntax_score(),
+        _ => 0
+    }).sum::<u64>().into()
+}
+
+pub fn part2(results: &Input) -> Answer {
+    let mut auto_scores: Vec<u64> = results.iter().filter_map(|r| match r {
+        ParseResult::Incomplete { open } => Some(autocomplete_score(open)),
+        _ => None
+    }).collect();
+    auto_scores.sort();
+    auto_scores[auto_scores.len()/2].into()
+}
+
+pub fn autocomplete_score(open: &[Bracket]) -> u64 {
+    let mut score: u64 = 0;
+    for close in open.iter().rev() {
+        score *= 5;
+        score += match close {
+            Bracket::Round => 1,
+            Bracket::Square => 2,
+            Bracket::Brace => 3,
+            Bracket::Arrow => 4
+        };
+    }
+    score
+}
+
+impl Bracket {
+    pub fn syntax_score(&self) -> u64 {
+        match self {
+            Bracket::Round => 3,
+            Bracket::Square => 57,
+            Bracket::Brace => 1197,
+            Bracket::Arrow => 25137
+        }
+    }
+
+    fn open(c: char) -> Option<Self> {
+        match c {
+            '(' => Some(Self::Round),
+            '[' => Some(Self::Square),
+            '{' => Some(Self::Brace),
+            '<' => Some(Self::Arrow),
+            _ => None
+        }
+    }
+
+    fn close(c: char) -> Option<Self> {
+        match c {
+            ')' => Some(Self::Round),
+            ']' => Some(Self::Square),
+            '}' => Some(Self::Brace),
+            '>' => Some(Self::Arrow),
+            _ => None
+        }
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<ParseResult, Error> {
+    let mut brackets = Vec::new();
+    for (i, c) in line.chars().enumerate() {
+        if let Some(close) = Bracket::close(c) {
+            let last = brackets.pop();
+            if last != Some(close) {
+                return Ok(ParseResult::Corrupted {
+                    found: close,
+                    expected: last
+                });
+            }
+        } else if let Some(open) = Bracket::open(c) {
+            brackets.push(open);
+        } else {
+            return Err(Error::InvalidChar(Span::char_at(line, i)));
+        }
+    }
+    if brackets.is_empty() {
+        Ok(ParseResult::Valid)
+    } else {
+        Ok(ParseResult::Incomplete { open: brackets })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, GridError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An energy level which isn't a single digit
+    InvalidEnergy(Span),
+    /// A row with a different number of values to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// Input without any rows
+    EmptyGrid
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidEnergy(span) => write!(f, "expected an energy level digit, found `{}`", span.text),
+            Error::InvalidRowLength { span, expected } => write!(f, "expected {} values in row, found `{}`", expected, span.text),
+            Error::EmptyGrid => write!(f, "expected at least one row of octopi")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidEnergy(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidEnergy(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
+
+impl From<GridError> for Error {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::InvalidCell(span) => Error::InvalidEnergy(span),
+            GridError::InvalidRowLength { span, expected } => Error::InvalidRowLength { span, expected },
+            GridError::Empty => Error::EmptyGrid
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use aoc_common::{grid, Rng};
+use crate::{Grid, Octopus};
+
+pub const DEFAULT_SIZE: usize = 10;
+
+/// Rounds to wait for every octopus to flash at once before giving up on a grid.
+const MAX_ROUNDS: usize = 5000;
+
+/// A `size` by `size` grid of energy levels, redrawn until the octopuses all flash together within a
+/// few thousand rounds, so that part 2 finishes.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+    loop {
+        let levels = grid::Grid::from_fn(size, size, |_| rng.between(0, 9) as u32);
+        let mut grid = Grid { octopi: levels.map(|&l| Octopus(l)) };
+        if (0..MAX_ROUNDS).any(|_| grid.increment_all().len() == grid.size()) {
+            return levels.to_string();
+        }
+    }
+}
\ No newline at end of file
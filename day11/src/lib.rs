@@ -0,0 +1,96 @@
+use std::str::FromStr;
+use aoc_common::{grid, Answer, Position};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[derive(Clone)]
+struct Octopus(u32);
+
+#[derive(Clone)]
+pub struct Grid {
+    octopi: grid::Grid<Octopus>
+}
+
+pub struct Flash(Position);
+
+pub type Input = Grid;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    text.parse()
+}
+
+/// Checks the grid, listing every energy level which isn't a digit and every row of the wrong length.
+pub fn validate(text: &str) -> Vec<Error> {
+    grid::Grid::validate(text, |e| e.to_digit(10)).into_iter().map(Error::from).collect()
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    count_flashes(grid, 100).into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    let mut grid = grid.clone();
+    let mut round = 1;
+    while grid.increment_all().len() != grid.size() {
+        round += 1;
+    }
+    round.into()
+}
+
+/// The total number of flashes over a number of rounds, starting from a copy of the grid.
+pub fn count_flashes(grid: &Grid, rounds: usize) -> usize {
+    let mut grid = grid.clone();
+    (0..rounds).map(|_| grid.increment_all().len()).sum()
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let octopi = grid::Grid::parse(text, |e| e.to_digit(10).map(Octopus))?;
+        Ok(Self { octopi })
+    }
+}
+
+impl Grid {
+    pub fn size(&self) -> usize {
+        self.octopi.width() * self.octopi.height()
+    }
+
+    pub fn increment_all(&mut self) -> Vec<Flash> {
+        let mut flashes = Vec::new();
+        for p in self.octopi.positions() {
+            flashes.append(&mut self.increment(p));
+        }
+        for flash in &flashes {
+            self.octopi[flash.0].reset();
+        }
+        flashes
+    }
+
+    fn increment(&mut self, position: Position) -> Vec<Flash> {
+        let mut flashes = Vec::new();
+        if self.octopi[position].increment() {
+            flashes.push(Flash(position));
+            let adjacent: Vec<Position> = self.octopi.neighbours8(position).collect();
+            for p in adjacent {
+                flashes.append(&mut self.increment(p));
+            }
+        }
+        flashes
+    }
+}
+
+impl Octopus {
+    fn increment(&mut self) -> bool {
+        self.0 += 1;
+        self.0 == 10 // return true if flash
+    }
+
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't two cave names separated by a dash
+    InvalidConnection(Span),
+    /// A connection between two big caves, which would allow infinitely many paths
+    BigCavesConnected(Span)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidConnection(span) => write!(f, "expected `<cave>-<cave>`, found `{}`", span.text),
+            Error::BigCavesConnected(span) => write!(f, "expected at most one big cave, found `{}`", span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidConnection(span) | Error::BigCavesConnected(span) => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidConnection(span) | Error::BigCavesConnected(span) => Some(span)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 10;
+
+/// A cave system of `size` caves (at least 1) besides the start and end, about a quarter of them big.
+/// Every small cave is joined to the system, and no big cave connects to another, which would allow
+/// endless paths.
+pub fn generate(seed: u64, size: usize) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let letters: Vec<char> = ('a'..='z').collect();
+    // Names are two letters, or longer once there are too many caves for two letters to name easily
+    let mut length = 2;
+    while 26usize.saturating_pow(length as u32) < size * 4 {
+        length += 1;
+    }
+    let mut names = Vec::new();
+    let mut taken = HashSet::new();
+    while names.len() < size {
+        let name: String = (0..length).map(|_| *rng.choose(&letters)).collect();
+        let big = rng.chance(0.25);
+        if name != "start" && name != "end" && taken.insert(name.clone()) {
+            names.push(if big { name.to_uppercase() } else { name });
+        }
+    }
+    let big = |name: &str| name.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+    let mut connections = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        // Connect each cave to an earlier one, or to the start, so that the whole system is reachable
+        let earlier: Vec<&String> = names[..i].iter().filter(|n| !(big(n) && big(name))).collect();
+        let to = if earlier.is_empty() || rng.chance(0.2) { "start" } else { rng.choose(&earlier).as_str() };
+        connections.push(format!("{}-{}", to, name));
+    }
+    for _ in 0..size / 2 {
+        let (a, b) = (rng.choose(&names), rng.choose(&names));
+        if a != b && !(big(a) && big(b)) {
+            connections.push(format!("{}-{}", a, b));
+        }
+    }
+    for _ in 0..2 {
+        connections.push(format!("{}-end", rng.choose(&names)));
+    }
+    rng.shuffle(&mut connections);
+    connections.join("\n")
+}
\ No newline at end of file
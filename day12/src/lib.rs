@@ -0,0 +1,174 @@
+use std::str::FromStr;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+struct Cave {
+    name: String,
+    size: CaveSize
+}
+
+#[derive(PartialEq)]
+enum CaveSize {
+    Big,
+    Small
+}
+
+pub struct System {
+    caves: HashMap<String, Cave>,
+    connections: HashMap<String, HashSet<String>>
+}
+
+pub struct Connection(String, String);
+
+#[derive(Clone)]
+struct Path {
+    order: Vec<String>,
+    visited: HashSet<String>,
+    double_visited: Option<String>
+}
+
+#[derive(PartialEq)]
+pub enum PathType {
+    AllSmallCavesOnce,
+    SingleSmallCaveTwice
+}
+
+pub type Input = System;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    Ok(System::new(&input::parse_lines(text)?))
+}
+
+/// Checks every line of the input, listing each which isn't a connection between two caves.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Connection, Error>(text)
+}
+
+pub fn part1(system: &Input) -> Answer {
+    system.count_paths(&PathType::AllSmallCavesOnce).into()
+}
+
+pub fn part2(system: &Input) -> Answer {
+    system.count_paths(&PathType::SingleSmallCaveTwice).into()
+}
+
+impl FromStr for Connection {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = line.split("-").collect();
+        if segments.len() != 2 || segments.iter().any(|s| s.is_empty()) {
+            Err(Error::InvalidConnection(Span::line(line)))
+        } else if segments.iter().all(|s| Cave::new(s.to_string()).size == CaveSize::Big) {
+            Err(Error::BigCavesConnected(Span::line(line)))
+        } else {
+            Ok(Self(segments[0].to_string(), segments[1].to_string()))
+        }
+    }
+}
+
+impl Validate for Connection {}
+
+impl System {
+    pub fn new(connections: &[Connection]) -> Self {
+        let mut system = System {
+            caves: HashMap::new(),
+            connections: HashMap::new()
+        };
+        for connection in connections {
+            system.add_cave(&connection.0);
+            system.add_cave(&connection.1);
+        }
+        for connection in connections {
+            system.add_connection(&connection.0, &connection.1);
+            system.add_connection(&connection.1, &connection.0);
+        }
+        system
+    }
+
+    fn add_cave(&mut self, name: &str) -> bool {
+        if self.caves.contains_key(name) {
+            false
+        } else {
+            self.caves.insert(name.to_string(), Cave::new(name.to_string()));
+            self.connections.insert(name.to_string(), HashSet::new());
+            true
+        }
+    }
+
+    fn add_connection(&mut self, from: &str, to: &str) {
+        let from_cave = self.caves.get(from).unwrap();
+        let to_cave = self.caves.get(to).unwrap();
+        if from_cave.size == CaveSize::Big && to_cave.size == CaveSize::Big {
+            panic!("Cannot connect big cave {} to big cave {}", from, to);
+        }
+        let cave_connections = self.connections.get_mut(from).unwrap();
+        cave_connections.insert(to.to_string());
+    }
+
+    pub fn count_paths(&self, path_type: &PathType) -> usize {
+        self.find_all_paths("start", "end", &Path::new(), path_type).len()
+    }
+
+    fn find_all_paths(&self, from: &str, to: &str, base_path: &Path, path_type: &PathType) -> Vec<Path> {
+        let mut path = base_path.clone();
+        if from == to {
+            return vec![path]; // this path is complete
+        }
+        let mut paths = Vec::new();
+        let cave = self.caves.get(from).unwrap();
+        if !path.visit(cave, path_type) {
+            return paths; // no valid paths
+        }
+        for next in self.connections.get(from).unwrap() {
+            paths.append(&mut self.find_all_paths(next, to, &path, path_type));
+        }
+        paths
+    }
+}
+
+impl Cave {
+    fn new(name: String) -> Self {
+        let size = if name.chars().next().unwrap().is_ascii_uppercase() {
+            CaveSize::Big
+        } else {
+            CaveSize::Small
+        };
+        Self { name, size }
+    }
+}
+
+impl Path {
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            visited: HashSet::new(),
+            double_visited: None
+        }
+    }
+
+    fn visit(&mut self, next: &Cave, path_type: &PathType) -> bool { // returns true if valid
+        if next.size == CaveSize::Small { // big caves can be visited more than once
+            if !self.visited.insert(next.name.to_string()) {
+                if *path_type == PathType::SingleSmallCaveTwice && self.double_visited.is_none() && next.name != "start" && next.name != "end" {
+                    self.double_visited = Some(next.name.to_string());
+                } else {
+                    return false;
+                }
+            }
+        }
+        self.order.push(next.name.to_string());
+        true
+    }
+}
\ No newline at end of file
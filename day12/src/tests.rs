@@ -0,0 +1,9 @@
+use super::*;
+
+#[test]
+fn generate_names_every_cave() {
+    for size in [0, 700] {
+        let text = generate::generate(0, size);
+        assert!(validate(&text).is_empty(), "size {} fails validation", size);
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A dot which isn't two whole numbers separated by a comma
+    InvalidDot(Span),
+    /// A fold instruction along something other than x or y
+    InvalidFold(Span),
+    /// Input without dots and folds separated by a blank line
+    MissingFolds,
+    /// Instructions without any folds
+    NoFolds
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidDot(span) => write!(f, "expected `<x>,<y>`, found `{}`", span.text),
+            Error::InvalidFold(span) => write!(f, "expected `fold along x=<n>` or `fold along y=<n>`, found `{}`", span.text),
+            Error::MissingFolds => write!(f, "expected dots and fold instructions separated by a blank line"),
+            Error::NoFolds => write!(f, "expected at least one fold instruction")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidDot(span) | Error::InvalidFold(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidDot(span) | Error::InvalidFold(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
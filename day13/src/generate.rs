@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 150;
+
+/// Paper with up to `size` dots and twelve folds. The dots are placed in the 40 by 6 area left after
+/// folding, then unfolded at random, so that no dot lies on a fold line or folds past the edge.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut folds = vec![false; 5];
+    folds.extend([true; 7]);
+    rng.shuffle(&mut folds);
+    // Work out each fold position back from the final size, doubling the paper each time
+    let (mut width, mut height) = (40, 6);
+    let mut positions = Vec::new();
+    for &horizontal in folds.iter().rev() {
+        if horizontal {
+            positions.push((true, height));
+            height = 2 * height + 1;
+        } else {
+            positions.push((false, width));
+            width = 2 * width + 1;
+        }
+    }
+    let mut dots = HashSet::new();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.below(40), rng.below(6));
+        for &(horizontal, position) in &positions {
+            if rng.chance(0.5) {
+                if horizontal {
+                    y = 2 * position - y;
+                } else {
+                    x = 2 * position - x;
+                }
+            }
+        }
+        dots.insert((x, y));
+    }
+    let mut dots: Vec<String> = dots.into_iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    dots.sort();
+    rng.shuffle(&mut dots);
+    let folds: Vec<String> = positions.iter().rev()
+        .map(|&(horizontal, position)| format!("fold along {}={}", if horizontal { "y" } else { "x" }, position))
+        .collect();
+    format!("{}\n\n{}", dots.join("\n"), folds.join("\n"))
+}
\ No newline at end of file
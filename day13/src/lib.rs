@@ -0,0 +1,190 @@
+use std::str::FromStr;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Answer, Diagnostic, Grid, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+pub struct Manual {
+    pub paper: Paper,
+    pub folds: Vec<Fold>
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Paper {
+    pub dots: HashSet<Point>
+}
+
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct Point {
+    x: usize,
+    y: usize
+}
+
+pub enum Fold {
+    Horizontal { y: usize },
+    Vertical { x: usize }
+}
+
+pub type Input = Manual;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let segments = input::numbered_sections(text);
+    if segments.len() != 2 {
+        return Err(Error::MissingFolds);
+    }
+    let paper: Paper = input::parse_section(segments[0])?;
+    let (offset, folds) = segments[1];
+    let folds: Vec<Fold> = input::parse_lines(folds).map_err(|e: Error| e.offset_lines(offset))?;
+    if folds.is_empty() {
+        return Err(Error::NoFolds);
+    }
+    Ok(Manual { paper, folds })
+}
+
+/// Checks the dots and folds without folding, listing every dot which isn't one and every problem with
+/// each fold.
+pub fn validate(text: &str) -> Vec<Error> {
+    let segments = input::numbered_sections(text);
+    if segments.len() != 2 {
+        return vec![Error::MissingFolds];
+    }
+    let mut errors: Vec<Error> = input::validate_lines::<Point, Error>(segments[0].1).into_iter()
+        .map(|e| e.offset_lines(segments[0].0)).collect();
+    errors.extend(input::validate_lines::<Fold, Error>(segments[1].1).into_iter().map(|e| e.offset_lines(segments[1].0)));
+    errors
+}
+
+pub fn part1(manual: &Input) -> Answer {
+    let mut paper = manual.paper.clone();
+    paper.fold(&manual.folds[0]);
+    paper.dots.len().into()
+}
+
+pub fn part2(manual: &Input) -> Answer {
+    let mut paper = manual.paper.clone();
+    for fold in &manual.folds {
+        paper.fold(fold);
+    }
+    format!("{:#}", paper).into()
+}
+
+impl FromStr for Paper {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let dots = input::parse_lines::<Point, Error>(text)?.into_iter().collect();
+        Ok(Self { dots })
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<usize> = line.split(",").map(|n| n.parse()
+            .map_err(|_| Error::InvalidDot(Span::line(line)))).collect::<Result<_, _>>()?;
+        if numbers.len() == 2 {
+            Ok(Self {
+                x: numbers[0],
+                y: numbers[1]
+            })
+        } else {
+            Err(Error::InvalidDot(Span::line(line)))
+        }
+    }
+}
+
+impl Validate for Point {}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (axis, position) = Self::parts(line)?;
+        let position = Self::parse_position(line, position)?;
+        match axis {
+            "fold along y" => Ok(Fold::Horizontal { y: position }),
+            "fold along x" => Ok(Fold::Vertical { x: position }),
+            _ => Err(Error::InvalidFold(Span::new(line, axis)))
+        }
+    }
+}
+
+impl Validate for Fold {
+    fn problems(line: &str) -> Vec<Error> {
+        let (axis, position) = match Self::parts(line) {
+            Ok(parts) => parts,
+            Err(e) => return vec![e]
+        };
+        let mut errors = Vec::new();
+        if axis != "fold along y" && axis != "fold along x" {
+            errors.push(Error::InvalidFold(Span::new(line, axis)));
+        }
+        errors.extend(Self::parse_position(line, position).err());
+        errors
+    }
+}
+
+impl Fold {
+    fn parts(line: &str) -> Result<(&str, &str), Error> {
+        line.split_once("=").filter(|(_, p)| !p.contains("=")).ok_or_else(|| Error::InvalidFold(Span::line(line)))
+    }
+
+    fn parse_position(line: &str, position: &str) -> Result<usize, Error> {
+        position.parse().map_err(|_| Error::InvalidFold(Span::new(line, position)))
+    }
+}
+
+impl Paper {
+    pub fn fold(&mut self, fold: &Fold) {
+        let mut new_dots = HashSet::new();
+        for p in &self.dots {
+            new_dots.insert(match fold {
+                Fold::Horizontal { y } if p.y > *y => Point { x: p.x, y: 2*y - p.y },
+                Fold::Vertical { x } if p.x > *x => Point { x: 2*x - p.x, y: p.y },
+                _ => *p
+            });
+        }
+        self.dots = new_dots;
+    }
+}
+
+/// Lists the dots in the same format that `parse` reads, or with `{:#}` draws the paper, with `#` for
+/// each dot.
+impl Display for Paper {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        if f.alternate() {
+            let width = self.dots.iter().map(|p| p.x + 1).max().unwrap_or(0);
+            let height = self.dots.iter().map(|p| p.y + 1).max().unwrap_or(0);
+            let grid = Grid::from_fn(width, height, |p| if self.dots.contains(&Point { x: p.x, y: p.y }) {
+                '#'
+            } else {
+                '.'
+            });
+            write!(f, "{}", grid)
+        } else {
+            let mut dots: Vec<&Point> = self.dots.iter().collect();
+            dots.sort_by_key(|p| (p.y, p.x));
+            for p in dots {
+                writeln!(f, "{}", p)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
\ No newline at end of file
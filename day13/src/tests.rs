@@ -0,0 +1,26 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn paper_round_trip() {
+    assert_round_trip(5, |seed| parse(&generate::generate(seed, 50)).unwrap().paper);
+}
+
+#[test]
+fn folded_paper_round_trip() {
+    let manual = parse(&generate::generate(0, 50)).unwrap();
+    let mut paper = manual.paper.clone();
+    assert_round_trip(manual.folds.len() as u64, |i| {
+        paper.fold(&manual.folds[i as usize]);
+        paper.clone()
+    });
+}
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("6,10\n0,x\n\nfold along y=7\nfold along z=q\nfold along x");
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(&errors[0], Error::InvalidDot(span) if span.line == 2));
+    assert!(matches!(&errors[1], Error::InvalidFold(span) if span.line == 5 && span.text == "fold along z"));
+    assert!(matches!(&errors[2], Error::InvalidFold(span) if span.line == 5 && span.text == "q"));
+    assert!(matches!(&errors[3], Error::InvalidFold(span) if span.line == 6));
+}
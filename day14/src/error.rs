@@ -0,0 +1,36 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An insertion rule which isn't a pair and an element
+    InvalidRule(Span),
+    /// Input without a template and rules separated by a blank line
+    MissingRules
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidRule(span) => write!(f, "expected `<pair> -> <element>`, found `{}`", span.text),
+            Error::MissingRules => write!(f, "expected a template and insertion rules separated by a blank line")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidRule(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidRule(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
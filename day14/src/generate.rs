@@ -0,0 +1,20 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 20;
+
+/// A polymer template of `size` elements from ten, and an insertion rule for every pair of those elements.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut letters: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut letters);
+    let elements = &letters[..10];
+    let template: String = (0..size.max(1)).map(|_| *rng.choose(elements)).collect();
+    let mut rules = Vec::new();
+    for &a in elements {
+        for &b in elements {
+            rules.push(format!("{}{} -> {}", a, b, rng.choose(elements)));
+        }
+    }
+    rng.shuffle(&mut rules);
+    format!("{}\n\n{}", template, rules.join("\n"))
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+use std::collections::HashMap;
+use aoc_common::{Answer, Diagnostic, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+pub struct Propogation {
+    pair: (char, char),
+    create: char
+}
+
+pub struct Polymer {
+    pub template: Vec<char>,
+    pub map: PropogationMap
+}
+
+#[derive(Clone)]
+pub struct PropogationMap {
+    map: HashMap<(char, char), char>,
+    cached_inner: HashMap<(char, char, usize), HashMap<char, usize>>
+}
+
+pub type Input = Polymer;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let segments = input::numbered_sections(text);
+    if segments.len() != 2 {
+        return Err(Error::MissingRules);
+    }
+    let template: Vec<char> = segments[0].1.chars().collect();
+    let (offset, rules) = segments[1];
+    let propogations: Vec<Propogation> = input::parse_lines(rules).map_err(|e: Error| e.offset_lines(offset))?;
+    Ok(Polymer { template, map: PropogationMap::new(&propogations) })
+}
+
+/// Checks the template and rules, listing every rule which isn't a pair and an element.
+pub fn validate(text: &str) -> Vec<Error> {
+    let segments = input::numbered_sections(text);
+    if segments.len() != 2 {
+        return vec![Error::MissingRules];
+    }
+    let (offset, rules) = segments[1];
+    input::validate_lines::<Propogation, Error>(rules).into_iter().map(|e| e.offset_lines(offset)).collect()
+}
+
+pub fn part1(polymer: &Input) -> Answer {
+    spread(&polymer.counts_after(10)).into()
+}
+
+pub fn part2(polymer: &Input) -> Answer {
+    spread(&polymer.counts_after(40)).into()
+}
+
+/// The difference between the most and least common element counts.
+pub fn spread(counts: &HashMap<char, usize>) -> usize {
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+impl FromStr for Propogation {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        //CN -> C
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() == 7 && chars[2..6] == [' ', '-', '>', ' '] {
+            Ok(Propogation {
+                pair: (chars[0], chars[1]),
+                create: chars[6]
+            })
+        } else{
+            Err(Error::InvalidRule(Span::line(text)))
+        }
+    }
+}
+
+impl Validate for Propogation {}
+
+impl Polymer {
+    /// The count of each element after a number of steps, using a copy of the propogation map.
+    pub fn counts_after(&self, steps: usize) -> HashMap<char, usize> {
+        self.map.clone().propogate(&self.template, steps)
+    }
+}
+
+impl PropogationMap {
+    pub fn new(propogations: &[Propogation]) -> Self {
+        let mut map = HashMap::new();
+        for propogation in propogations {
+            map.insert(propogation.pair, propogation.create);
+        }
+        PropogationMap {
+            map,
+            cached_inner: HashMap::new()
+        }
+    }
+    
+    pub fn propogate(&mut self, template: &[char], steps: usize) -> HashMap<char, usize> {
+        let mut previous = template[0];
+        let mut counts = HashMap::new();
+        increment(&mut counts, previous, 1);
+        for &next in &template[1..] {
+            increment(&mut counts, next, 1);
+            combine(&mut counts, &self.inner(previous, next, steps));
+            previous = next;
+        }
+        counts
+    }
+
+    fn inner(&mut self, previous: char, next: char, steps: usize) -> HashMap<char, usize> {
+        if let Some(cached) = self.cached_inner.get(&(previous, next, steps)) {
+            cached.clone()
+        } else {
+            let mut counts = HashMap::new();
+            if steps > 0 {
+                if let Some(&create) = self.map.get(&(previous, next)) {
+                    combine(&mut counts, &self.inner(previous, create, steps - 1));
+                    increment(&mut counts, create, 1);
+                    combine(&mut counts, &self.inner(create, next, steps - 1));
+                } else {
+                    // nothing gets added here
+                }
+            }
+            self.cached_inner.insert((previous, next, steps), counts.clone());
+            counts
+        }
+    }
+}
+
+fn increment(counts: &mut HashMap<char, usize>, key: char, delta: usize) {
+    if let Some(existing) = counts.get(&key) {
+        counts.insert(key, existing + delta);
+    } else {
+        counts.insert(key, delta);
+    }
+}
+
+fn combine(counts: &mut HashMap<char, usize>, with: &HashMap<char, usize>) {
+    for (&k, &v) in with {
+        increment(counts, k, v);
+    }
+}
\ No newline at end of file
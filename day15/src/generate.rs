@@ -0,0 +1,9 @@
+use aoc_common::{grid, Rng};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` grid of risk levels from 1 to 9.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    grid::Grid::from_fn(size.max(1), size.max(1), |_| rng.between(1, 9)).to_string()
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use std::str::FromStr;
+use pathfinding::prelude::astar;
+use aoc_common::{grid, Answer, Position};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+pub struct Grid {
+    risk: grid::Grid<u8>
+}
+
+pub type Input = Grid;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    text.parse()
+}
+
+/// Checks the grid, listing every risk level which isn't a digit and every row of the wrong length.
+pub fn validate(text: &str) -> Vec<Error> {
+    grid::Grid::validate(text, |r| r.to_digit(10)).into_iter().map(Error::from).collect()
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    let (_, risk) = lowest_risk_path(grid);
+    risk.into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    let (_, risk) = lowest_risk_path(&grid.expand(5, 5));
+    risk.into()
+}
+
+/// Finds the path from top left to bottom right with the lowest total risk, returning its length and risk.
+pub fn lowest_risk_path(grid: &Grid) -> (usize, usize) {
+    let start = Position::new(0, 0);
+    let end = Position::new(grid.risk.width() - 1, grid.risk.height() - 1);
+    let path = astar(
+        &start,
+        |&p| grid.adjacent_risks(p),
+        |p| p.distance(&end),
+        |p| *p == end
+    ).expect("every cell of a grid is reachable");
+    (path.0.len(), path.1)
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let risk = grid::Grid::parse(text, |r| r.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { risk })
+    }
+}
+
+impl Grid {
+    fn adjacent_risks(&self, position: Position) -> Vec<(Position, usize)> {
+        self.risk.neighbours4(position).map(|p| (p, self.risk[p] as usize)).collect()
+    }
+
+    pub fn expand(&self, scale_x: u8, scale_y: u8) -> Grid {
+        let (width, height) = (self.risk.width(), self.risk.height());
+        let risk = grid::Grid::from_fn(width * scale_x as usize, height * scale_y as usize, |p| {
+            let tile = (p.x / width + p.y / height) as u8;
+            Self::increase_risk(self.risk[Position::new(p.x % width, p.y % height)], tile)
+        });
+        Grid { risk }
+    }
+
+    fn increase_risk(risk_level: u8, increase_by: u8) -> u8 {
+        let mut new_level = risk_level + increase_by;
+        while new_level > 9 {
+            new_level -= 9;
+        }
+        new_level
+    }
+}
\ No newline at end of file
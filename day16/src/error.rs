@@ -0,0 +1,52 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A character in a transmission which isn't a hex digit
+    InvalidHex(Span),
+    /// A transmission which doesn't decode to a valid packet
+    InvalidPacket { span: Span, error: PacketError }
+}
+
+/// Why the bits of a transmission couldn't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketError {
+    /// The bits ran out part way through a packet
+    Truncated,
+    /// A comparison operator without exactly 2 sub-packets
+    InvalidComparison { found: usize }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidHex(span) => write!(f, "expected a hex digit, found `{}`", span.text),
+            Error::InvalidPacket { span, error } => write!(f, "{} in transmission `{}`", error, span.text)
+        }
+    }
+}
+
+impl Display for PacketError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            PacketError::Truncated => write!(f, "expected more bits"),
+            PacketError::InvalidComparison { found } => write!(f, "expected 2 sub-packets for a comparison, found {}", found)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidHex(span) | Error::InvalidPacket { span, .. } => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidHex(span) | Error::InvalidPacket { span, .. } => Some(span)
+        }
+    }
+}
\ No newline at end of file
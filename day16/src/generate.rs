@@ -0,0 +1,57 @@
+use aoc_common::Rng;
+use crate::{binary_to_hex, literal_to_binary};
+
+pub const DEFAULT_SIZE: usize = 60;
+
+/// Packet values are kept below this, well inside a `u64` like the real input.
+const MAX_VALUE: u128 = 1 << 48;
+
+/// A single transmission of a random packet tree holding about `size` literals, using every operator
+/// and both length types.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let (bits, _) = packet(&mut rng, size.max(1), 0);
+    binary_to_hex(&bits)
+}
+
+/// Encodes a packet with up to `budget` literals, returning its bits and value.
+fn packet(rng: &mut Rng, budget: usize, depth: usize) -> (String, u128) {
+    let version = rng.below(8);
+    if budget == 1 || depth >= 6 {
+        let bits = rng.below(16);
+        let value = rng.between(0, 1 << bits) as u128;
+        return (format!("{:03b}100{}", version, literal_to_binary(value)), value);
+    }
+    let mut type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if type_id >= 5 { 2 } else { rng.between(1, budget.min(5) as i64) as usize };
+    let mut children = Vec::new();
+    let mut remaining = budget;
+    for i in 0..count {
+        let share = remaining / (count - i);
+        remaining -= share;
+        children.push(packet(rng, share, depth + 1));
+    }
+    let values: Vec<u128> = children.iter().map(|(_, v)| *v).collect();
+    if type_id == 1 && values.iter().try_fold(1u128, |p, &v| p.checked_mul(v).filter(|&p| p < MAX_VALUE)).is_none() {
+        type_id = 0;
+    }
+    if type_id == 0 && values.iter().sum::<u128>() >= MAX_VALUE {
+        type_id = 3;
+    }
+    let value = match type_id {
+        0 => values.iter().sum(),
+        1 => values.iter().product(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as u128,
+        6 => (values[0] < values[1]) as u128,
+        _ => (values[0] == values[1]) as u128
+    };
+    let body: String = children.into_iter().map(|(bits, _)| bits).collect();
+    let length = if rng.chance(0.5) {
+        format!("0{:015b}", body.len())
+    } else {
+        format!("1{:011b}", count)
+    };
+    (format!("{:03b}{:03b}{}{}", version, type_id, length, body), value)
+}
\ No newline at end of file
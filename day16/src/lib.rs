@@ -0,0 +1,305 @@
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::{Error, PacketError};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(PartialEq)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    message: Message
+}
+
+#[derive(PartialEq)]
+enum Message {
+    Literal(u128),
+    Sum(Vec<Packet>),
+    Product(Vec<Packet>),
+    Min(Vec<Packet>),
+    Max(Vec<Packet>),
+    GreaterThan(Box<Packet>, Box<Packet>),
+    LessThan(Box<Packet>, Box<Packet>),
+    EqualTo(Box<Packet>, Box<Packet>)
+}
+
+pub type Input = Vec<Packet>;
+
+/// Decodes one packet from each line of hex.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing each transmission which doesn't decode to a packet.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Packet, Error>(text)
+}
+
+pub fn part1(packets: &Input) -> Answer {
+    combine(packets.iter().map(|p| p.version_sum() as u128).collect())
+}
+
+pub fn part2(packets: &Input) -> Answer {
+    combine(packets.iter().map(|p| p.value()).collect())
+}
+
+/// A single transmission answers with its value, several with a comma separated list.
+fn combine(values: Vec<u128>) -> Answer {
+    if values.len() == 1 {
+        values[0].into()
+    } else {
+        values.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",").into()
+    }
+}
+
+impl FromStr for Packet {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let binary = hex_to_binary(line).map_err(|c| Error::InvalidHex(Span::char_at(line, c)))?;
+        Packet::from_stream(&mut binary.chars())
+            .map_err(|error| Error::InvalidPacket { span: Span::line(line), error })
+    }
+}
+
+impl Validate for Packet {}
+
+/// Encodes the packet as a line of hex, in the same format that `parse` reads, or with `{:#}` shows
+/// the tree of packets.
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        if !f.alternate() {
+            return write!(f, "{}", binary_to_hex(&self.to_binary()));
+        }
+        const TAB: &str = "  ";
+        let type_name = match self.type_id {
+            4 => "Literal".to_string(),
+            id => format!("Operator({})", id)
+        };
+        writeln!(f, "{} Packet v{}:", type_name, self.version)?;
+        let message = format!("{}", self.message);
+        let lines: Vec<String> = message.lines().map(|l| format!("{}{}", TAB, l)).collect();
+        write!(f, "{}", lines.join("\r\n"))?;
+        Ok(())
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Message::Literal(value) => writeln!(f, "{}", value),
+            Message::Sum(packets) => writeln!(f, "{}", packets.iter().map(|p| format!("{:#}", p)).collect::<Vec<String>>().join("\r\n")),
+            Message::Product(packets) => writeln!(f, "{}", packets.iter().map(|p| format!("{:#}", p)).collect::<Vec<String>>().join("\r\n")),
+            Message::Min(packets) => writeln!(f, "{}", packets.iter().map(|p| format!("{:#}", p)).collect::<Vec<String>>().join("\r\n")),
+            Message::Max(packets) => writeln!(f, "{}", packets.iter().map(|p| format!("{:#}", p)).collect::<Vec<String>>().join("\r\n")),
+            Message::GreaterThan(a, b) => writeln!(f, "{:#}\r\n{:#}", a, b),
+            Message::LessThan(a, b) => writeln!(f, "{:#}\r\n{:#}", a, b),
+            Message::EqualTo(a, b) => writeln!(f, "{:#}\r\n{:#}", a, b)
+        }
+    }
+}
+
+impl Packet {
+    pub fn from_stream(stream: &mut dyn Iterator<Item = char>) -> Result<Self, PacketError> {
+        let version = read_number(stream, 3)? as u8;
+        let type_id = read_number(stream, 3)? as u8;
+        let message = Message::from_stream(stream, type_id)?;
+        Ok(Self { version, type_id, message })
+    }
+
+    /// Encodes the packet as binary digits, giving operators the number of sub-packets rather than
+    /// their length in bits.
+    pub fn to_binary(&self) -> String {
+        let mut binary = format!("{:03b}{:03b}", self.version, self.type_id);
+        match &self.message {
+            Message::Literal(value) => binary.push_str(&literal_to_binary(*value)),
+            message => {
+                let sub_packets = message.sub_packets();
+                binary.push_str(&format!("1{:011b}", sub_packets.len()));
+                for packet in sub_packets {
+                    binary.push_str(&packet.to_binary());
+                }
+            }
+        }
+        binary
+    }
+
+    pub fn version_sum(&self) -> usize {
+        self.version as usize + self.message.version_sum()
+    }
+
+    pub fn value(&self) -> u128 {
+        self.message.value()
+    }
+}
+
+impl Message {
+    fn from_stream(stream: &mut dyn Iterator<Item = char>, type_id: u8) -> Result<Self, PacketError> {
+        Ok(match type_id {
+            4 => Message::Literal(Self::read_literal(stream)?),
+            _ => {
+                let length_bit = read_number(stream, 1)?;
+                let mut sub_packets = Vec::new();
+                if length_bit == 0 {
+                    let total_length = read_number(stream, 15)?;
+                    let mut remaining: Vec<char> = stream.take(total_length as usize).collect();
+                    if remaining.len() != total_length as usize {
+                        return Err(PacketError::Truncated);
+                    }
+                    while !remaining.is_empty() {
+                        let mut remaining_stream = remaining.into_iter();
+                        sub_packets.push(Packet::from_stream(&mut remaining_stream)?);
+                        remaining = remaining_stream.collect();
+                    }
+                } else {
+                    let total_sub_packets = read_number(stream, 11)?;
+                    for _ in 0..total_sub_packets {
+                        sub_packets.push(Packet::from_stream(stream)?)
+                    }
+                }
+                match type_id {
+                    0 => Message::Sum(sub_packets),
+                    1 => Message::Product(sub_packets),
+                    2 => Message::Min(sub_packets),
+                    3 => Message::Max(sub_packets),
+                    _ => {
+                        if sub_packets.len() != 2 {
+                            return Err(PacketError::InvalidComparison { found: sub_packets.len() });
+                        }
+                        let mut iter = sub_packets.into_iter();
+                        let a = iter.next().unwrap();
+                        let b = iter.next().unwrap();
+                        match type_id {
+                            5 => Message::GreaterThan(Box::new(a), Box::new(b)),
+                            6 => Message::LessThan(Box::new(a), Box::new(b)),
+                            _ => Message::EqualTo(Box::new(a), Box::new(b))
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn read_literal(stream: &mut dyn Iterator<Item = char>) -> Result<u128, PacketError> {
+        let mut num_val: u128 = 0;
+        let mut last_byte = false;
+        while !last_byte {
+            last_byte = read_number(stream, 1)? == 0;
+            num_val = (num_val << 4) + read_number(stream, 4)? as u128;
+        }
+        Ok(num_val)
+    }
+
+    fn sub_packets(&self) -> Vec<&Packet> {
+        match self {
+            Message::Literal(_) => Vec::new(),
+            Message::Sum(packets) | Message::Product(packets) | Message::Min(packets) | Message::Max(packets) => packets.iter().collect(),
+            Message::GreaterThan(a, b) | Message::LessThan(a, b) | Message::EqualTo(a, b) => vec![a, b]
+        }
+    }
+
+    fn value(&self) -> u128 {
+        match self {
+            Message::Literal(literal) => *literal,
+            Message::Sum(packets) => packets.iter().map(|p| p.value()).sum::<u128>(),
+            Message::Product(packets) => packets.iter().map(|p| p.value()).product::<u128>(),
+            Message::Min(packets) => packets.iter().map(|p| p.value()).min().unwrap(),
+            Message::Max(packets) => packets.iter().map(|p| p.value()).max().unwrap(),
+            Message::GreaterThan(a, b) => if a.value() > b.value() { 1 } else { 0 },
+            Message::LessThan(a, b) => if a.value() < b.value() { 1 } else { 0 },
+            Message::EqualTo(a, b) => if  a.value() == b.value() { 1 } else { 0 }
+        }
+    }
+
+    fn version_sum(&self) -> usize {
+        match self {
+            Message::Literal(_) => 0,
+            Message::Sum(packets) => packets.iter().map(|p| p.version_sum()).sum::<usize>(),
+            Message::Product(packets) => packets.iter().map(|p| p.version_sum()).sum::<usize>(),
+            Message::Min(packets) => packets.iter().map(|p| p.version_sum()).sum::<usize>(),
+            Message::Max(packets) => packets.iter().map(|p| p.version_sum()).sum::<usize>(),
+            Message::GreaterThan(a, b) => a.version_sum() + b.version_sum(),
+            Message::LessThan(a, b) => a.version_sum() + b.version_sum(),
+            Message::EqualTo(a, b) => a.version_sum() + b.version_sum()
+        }
+    }
+}
+
+/// Reads a big-endian number from the next bits of a stream.
+fn read_number(stream: &mut dyn Iterator<Item = char>, bits: usize) -> Result<u32, PacketError> {
+    let mut number = 0;
+    for _ in 0..bits {
+        number = (number << 1) + match stream.next() {
+            Some('1') => 1,
+            Some(_) => 0,
+            None => return Err(PacketError::Truncated)
+        };
+    }
+    Ok(number)
+}
+
+/// Encodes a literal value in groups of four bits, each prefixed with whether another group follows.
+pub(crate) fn literal_to_binary(value: u128) -> String {
+    let mut groups = Vec::new();
+    let mut value = value;
+    loop {
+        groups.push(value & 0xf);
+        value >>= 4;
+        if value == 0 {
+            break;
+        }
+    }
+    groups.iter().rev().enumerate()
+        .map(|(i, g)| format!("{}{:04b}", if i + 1 == groups.len() { 0 } else { 1 }, g))
+        .collect()
+}
+
+/// Packs a string of binary digits into hex, padding the end with zeros to a whole number of digits.
+pub(crate) fn binary_to_hex(binary: &str) -> String {
+    let mut binary = binary.to_string();
+    while !binary.len().is_multiple_of(4) {
+        binary.push('0');
+    }
+    binary.as_bytes().chunks(4)
+        .map(|nibble| format!("{:X}", u8::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap()))
+        .collect()
+}
+
+/// Expands hex to a string of binary digits, or returns the char index of the first invalid digit.
+fn hex_to_binary(hex_str: &str) -> Result<String, usize> {
+    let mut result = vec![];
+    for (i, hex_c) in hex_str.chars().enumerate() {
+        let bin_str = match hex_c {
+            '0' => "0000",
+            '1' => "0001",
+            '2' => "0010",
+            '3' => "0011",
+            '4' => "0100",
+            '5' => "0101",
+            '6' => "0110",
+            '7' => "0111",
+            '8' => "1000",
+            '9' => "1001",
+            'A' => "1010",
+            'B' => "1011",
+            'C' => "1100",
+            'D' => "1101",
+            'E' => "1110",
+            'F' => "1111",
+            _ => return Err(i)
+        };
+        for bin_c in bin_str.chars() {
+            result.push(bin_c);
+        }
+    }
+    Ok(result.into_iter().collect())
+}
\ No newline at end of file
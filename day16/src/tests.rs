@@ -0,0 +1,7 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn packet_round_trip() {
+    assert_round_trip(10, |seed| generate::generate(seed, 20).parse::<Packet>().unwrap());
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A description which isn't of an x range and y range
+    InvalidTargetArea(Span),
+    /// A range bound which isn't a whole number
+    InvalidNumber(Span),
+    /// A target which isn't below the launcher, so can't be searched exhaustively
+    TargetAboveLauncher { min_y: isize, max_y: isize },
+    /// A target behind the launcher or with reversed ranges, which no velocity hits
+    NoHits
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidTargetArea(span) => write!(f, "expected `target area: x=<min>..<max>, y=<min>..<max>`, found `{}`", span.text),
+            Error::InvalidNumber(span) => write!(f, "expected a number, found `{}`", span.text),
+            Error::TargetAboveLauncher { min_y, max_y } => write!(f, "expected the target area to be below the launcher, found y={}..{}", min_y, max_y),
+            Error::NoHits => write!(f, "expected a target area ahead of the launcher with ascending ranges")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidTargetArea(span) | Error::InvalidNumber(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidTargetArea(span) | Error::InvalidNumber(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 200;
+
+/// A target area ahead of and below the launcher, within `size` of it in both directions. Any such
+/// target can be hit, if only by firing straight at it.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(10) as i64;
+    let x_min = rng.between(1, size - 5);
+    let x_max = rng.between(x_min, (x_min + size / 5).min(size));
+    let y_max = -rng.between(5, size - 5);
+    let y_min = rng.between(-size, y_max);
+    format!("target area: x={}..{}, y={}..{}", x_min, x_max, y_min, y_max)
+}
\ No newline at end of file
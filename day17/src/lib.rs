@@ -0,0 +1,153 @@
+use aoc_common::{Answer, Span};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+struct Point {
+    x: isize,
+    y: isize
+}
+
+struct Probe {
+    position: Point,
+    velocity: Point
+}
+
+pub struct Area {
+    min: Point,
+    max: Point
+}
+
+enum ProbeResult {
+    MissedShort,
+    MissedLong,
+    Hit { max_height: isize }
+}
+
+/// A launch velocity which hits the target, and the highest point reached on the way.
+pub struct Hit {
+    pub velocity: (isize, isize),
+    pub max_height: isize
+}
+
+pub type Input = Area;
+
+pub fn part1(target: &Input) -> Answer {
+    let hits = find_hits(target);
+    hits.iter().map(|h| h.max_height).max().expect("parse checks that the target can be hit").into()
+}
+
+pub fn part2(target: &Input) -> Answer {
+    find_hits(target).len().into()
+}
+
+/// Fires the probe at every velocity which could possibly hit the target, returning those which do.
+pub fn find_hits(target: &Area) -> Vec<Hit> {
+    let mut hits = Vec::new();
+    for vy in target.min.y..-target.min.y {
+        for vx in 0..(target.max.x+1) {
+            let mut probe = Probe::new(vx, vy);
+            if let ProbeResult::Hit { max_height } = probe.fire(target) {
+                hits.push(Hit { velocity: (vx, vy), max_height });
+            }
+        }
+    }
+    hits
+}
+
+impl Probe {
+    fn new(x_velocity: isize, y_velocity: isize) -> Self {
+        Self {
+            position: Point {
+                x: 0,
+                y: 0
+            },
+            velocity: Point {
+                x: x_velocity,
+                y: y_velocity
+            }
+        }
+    }
+
+    fn step(&mut self) {
+        self.position.x += self.velocity.x;
+        self.position.y += self.velocity.y;
+        self.velocity.x -= self.velocity.x.signum();
+        self.velocity.y -= 1;
+    }
+
+    fn fire(&mut self, target: &Area) -> ProbeResult {
+        let mut max_height = self.position.y;
+        loop {
+            self.step();
+            if self.position.y > max_height {
+                max_height = self.position.y;
+            }
+            if target.contains(&self.position) {
+                return ProbeResult::Hit { max_height };
+            }
+            if self.position.x > target.max.x {
+                return ProbeResult::MissedLong;
+            }
+            if self.position.y < target.min.y {
+                return ProbeResult::MissedShort;
+            }
+        }
+    }
+}
+
+impl Area {
+    fn contains(&self, point: &Point) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x
+            && point.y >= self.min.y && point.y <= self.max.y
+    }
+}
+
+/// Parses a target area, checking that it can be searched for hits. Any upwards velocity of at least
+/// `-target.min.y` comes back down through y=0 fast enough to skip straight past the target, so only
+/// targets below the launcher can be searched exhaustively. Such a target is always hit by firing
+/// straight at its nearest corner, unless it's behind the launcher.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    //target area: x=20..30, y=-10..-5
+    let line = text.trim();
+    let coordinates: Vec<&str> = line.strip_prefix("target area: ")
+        .ok_or_else(|| Error::InvalidTargetArea(Span::line(line)))?.split(", ").collect();
+    if coordinates.len() != 2 {
+        return Err(Error::InvalidTargetArea(Span::line(line)));
+    }
+    let x_range = parse_range(line, coordinates[0], "x=")?;
+    let y_range = parse_range(line, coordinates[1], "y=")?;
+    let target = Area {
+        min: Point {
+            x: x_range[0],
+            y: y_range[0]
+        },
+        max: Point {
+            x: x_range[1],
+            y: y_range[1]
+        }
+    };
+    if target.min.y >= 0 {
+        return Err(Error::TargetAboveLauncher { min_y: target.min.y, max_y: target.max.y });
+    }
+    if target.min.x < 0 || target.min.x > target.max.x || target.min.y > target.max.y {
+        return Err(Error::NoHits);
+    }
+    Ok(target)
+}
+
+/// Checks the target area, which is a single description and so has at most one problem.
+pub fn validate(text: &str) -> Vec<Error> {
+    parse(text).err().into_iter().collect()
+}
+
+fn parse_range(line: &str, coordinate: &str, prefix: &str) -> Result<Vec<isize>, Error> {
+    let range: Vec<&str> = coordinate.strip_prefix(prefix)
+        .ok_or_else(|| Error::InvalidTargetArea(Span::new(line, coordinate)))?.split("..").collect();
+    if range.len() != 2 {
+        return Err(Error::InvalidTargetArea(Span::new(line, coordinate)));
+    }
+    range.into_iter().map(|n| n.parse().map_err(|_| Error::InvalidNumber(Span::new(line, n)))).collect()
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Something which is neither a pair nor a regular number
+    InvalidNumber(Span),
+    /// A pair without a comma separating its elements
+    MissingComma(Span),
+    /// Homework with fewer than two numbers to add
+    TooFewNumbers
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidNumber(span) => write!(f, "expected a pair or regular number, found `{}`", span.text),
+            Error::MissingComma(span) => write!(f, "expected a comma separating the pair `{}`", span.text),
+            Error::TooFewNumbers => write!(f, "expected at least 2 numbers")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidNumber(span) | Error::MissingComma(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidNumber(span) | Error::MissingComma(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
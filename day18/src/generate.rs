@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// `size` (at least two) snailfish numbers, already reduced: no pair is nested inside four others, and
+/// every regular number is a single digit.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    (0..size.max(2)).map(|_| pair(&mut rng, 1)).collect::<Vec<_>>().join("\n")
+}
+
+fn pair(rng: &mut Rng, depth: usize) -> String {
+    let element = |rng: &mut Rng| if depth < 4 && rng.chance(0.6) {
+        pair(rng, depth + 1)
+    } else {
+        rng.between(0, 9).to_string()
+    };
+    let left = element(rng);
+    let right = element(rng);
+    format!("[{},{}]", left, right)
+}
\ No newline at end of file
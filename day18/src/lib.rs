@@ -0,0 +1,230 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(PartialEq, Eq)]
+pub enum Number {
+    Literal(usize),
+    Pair(Box<Number>, Box<Number>)
+}
+
+struct Explosion {
+    left: Option<usize>,
+    right: Option<usize>
+}
+
+pub type Input = Vec<Number>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let numbers: Vec<Number> = input::parse_lines(text)?;
+    if numbers.len() < 2 {
+        return Err(Error::TooFewNumbers);
+    }
+    Ok(numbers)
+}
+
+/// Checks every line of the input, listing each which isn't a snailfish number, and that there are
+/// at least two numbers to add.
+pub fn validate(text: &str) -> Vec<Error> {
+    let mut errors = input::validate_lines::<Number, Error>(text);
+    if input::lines(text).count() < 2 {
+        errors.push(Error::TooFewNumbers);
+    }
+    errors
+}
+
+pub fn part1(numbers: &Input) -> Answer {
+    sum(numbers).magnitude().into()
+}
+
+pub fn part2(numbers: &Input) -> Answer {
+    best_magnitude_of_two(numbers).into()
+}
+
+pub fn sum(numbers: &[Number]) -> Number {
+    let mut result = Number::add(numbers[0].clone(), numbers[1].clone());
+    for number in &numbers[2..] {
+        result = Number::add(result, number.clone());
+    }
+    result
+}
+
+/// The largest magnitude from adding any two different numbers, in either order.
+pub fn best_magnitude_of_two(numbers: &[Number]) -> usize {
+    let mut max_magnitude = 0;
+    for x in 0..numbers.len() {
+        for y in 0..numbers.len() {
+            if x != y {
+                let result = Number::add(numbers[x].clone(), numbers[y].clone());
+                let magnitude = result.magnitude();
+                if magnitude > max_magnitude {
+                    max_magnitude = magnitude;
+                }
+            }
+        }
+    }
+    max_magnitude
+}
+
+impl FromStr for Number {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Self::parse(line, line)
+    }
+}
+
+impl Validate for Number {}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Number::Literal(l) => write!(f, "{}", l),
+            Number::Pair(a, b) => write!(f, "[{},{}]", a, b),
+        }
+    }
+}
+
+fn find_real_comma(line: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+impl Number {
+    /// Parses part of a line, so that errors can point at where in the line they occurred.
+    fn parse(line: &str, part: &str) -> Result<Self, Error> {
+        if part.len() > 2 && part.starts_with('[') && part.ends_with(']') {
+            let inner = &part[1..(part.len()-1)];
+            let comma = find_real_comma(inner).ok_or_else(|| Error::MissingComma(Span::new(line, part)))?;
+            Ok(Number::Pair(Box::new(Self::parse(line, &inner[0..comma])?), Box::new(Self::parse(line, &inner[(comma+1)..])?)))
+        } else {
+            Ok(Number::Literal(part.parse().map_err(|_| Error::InvalidNumber(Span::new(line, part)))?))
+        }
+    }
+
+    fn add(a: Number, b: Number) -> Number {
+        let mut n = Number::Pair(Box::new(a), Box::new(b));
+        while n.reduce() {}
+        n
+    }
+
+    fn reduce(&mut self) -> bool {
+        self.explode_nested_pair(4).is_some() || self.split_literal(10)
+    }
+
+    fn explode_nested_pair(&mut self, at_depth: usize) -> Option<Explosion> {
+        match self {
+            Number::Literal(_) => None,
+            Number::Pair(a, b) if at_depth == 0 => {
+                // explode
+                let a_value = a.as_literal().unwrap();
+                let b_value = b.as_literal().unwrap();
+                *self = Number::Literal(0);
+                Some(Explosion {
+                    left: Some(a_value),
+                    right: Some(b_value)
+                })
+            },
+            Number::Pair(a, b) => {
+                if let Some(explosion) = a.explode_nested_pair(at_depth - 1) {
+                    Some(b.consume_right(explosion))
+                } else {
+                    b.explode_nested_pair(at_depth - 1).map(|explosion| a.consume_left(explosion))
+                }
+            }
+        }
+    }
+
+    fn consume_right(&mut self, explosion: Explosion) -> Explosion {
+        if let Some(right) = explosion.right {
+            match self {
+                Number::Literal(l) => {
+                    *l += right;
+                    Explosion {
+                        left: explosion.left,
+                        right: None
+                    }
+                },
+                Number::Pair(a, b) => {
+                    b.consume_right(a.consume_right(explosion))
+                }
+            }
+        } else {
+            explosion
+        }
+    }
+
+    fn consume_left(&mut self, explosion: Explosion) -> Explosion {
+        if let Some(left) = explosion.left {
+            match self {
+                Number::Literal(l) => {
+                    *l += left;
+                    Explosion {
+                        left: None,
+                        right: explosion.right
+                    }
+                },
+                Number::Pair(a, b) => {
+                    a.consume_left(b.consume_left(explosion))
+                }
+            }
+        } else {
+            explosion
+        }
+    }
+
+    fn split_literal(&mut self, min_value: usize) -> bool {
+        match self {
+            Number::Literal(l) if *l >= min_value => {
+                // split
+                *self = Number::Pair(Box::new(Number::Literal(*l/2)), Box::new(Number::Literal(l.div_ceil(2))));
+                true
+            },
+            Number::Literal(_) => false,
+            Number::Pair(a, b) => a.split_literal(min_value) || b.split_literal(min_value)
+        }
+    }
+
+    fn as_literal(&self) -> Option<usize> {
+        match self {
+            Number::Literal(l) => Some(*l),
+            Number::Pair(_, _) => None
+        }
+    }
+
+    pub fn magnitude(&self) -> usize {
+        match self {
+            Number::Literal(l) => *l,
+            Number::Pair(a, b) => 3 * a.magnitude() + 2 * b.magnitude()
+        }
+    }
+}
+
+impl Clone for Number
+{
+    fn clone(&self) -> Self {
+        match self {
+            Number::Literal(l) => Number::Literal(*l),
+            Number::Pair(a, b) => Number::Pair(Box::new(*a.clone()), Box::new(*b.clone()))
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn number_round_trip() {
+    assert_round_trip(200, |seed| generate::generate(seed, 2).lines().next().unwrap().parse::<Number>().unwrap());
+}
+
+#[test]
+fn sum_round_trip() {
+    assert_round_trip(50, |seed| sum(&parse(&generate::generate(seed, 5)).unwrap()));
+}
\ No newline at end of file
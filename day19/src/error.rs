@@ -0,0 +1,42 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A scanner section which doesn't start with its name
+    InvalidHeader(Span),
+    /// A beacon which isn't three whole numbers separated by commas
+    InvalidBeacon(Span),
+    /// Input without any scanners
+    NoScanners,
+    /// Scanners which don't overlap enough with any located scanner
+    UnmatchedScanners { remaining: usize }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidHeader(span) => write!(f, "expected `--- scanner <n> ---`, found `{}`", span.text),
+            Error::InvalidBeacon(span) => write!(f, "expected `<x>,<y>,<z>`, found `{}`", span.text),
+            Error::NoScanners => write!(f, "expected at least one scanner"),
+            Error::UnmatchedScanners { remaining } => write!(f, "expected every scanner to overlap another, but {} could not be located", remaining)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidHeader(span) | Error::InvalidBeacon(span) => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidHeader(span) | Error::InvalidBeacon(span) => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
@@ -3,18 +3,19 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use crate::Point;
 
+#[derive(PartialEq)]
 pub struct FrameOfReference {
     pub position: Point,
     pub orientation: Orientation
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone)]
 pub struct Orientation {
     pub facing: Direction,
     pub up: Direction
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone)]
 pub enum Direction {
     X(bool),
     Y(bool),
@@ -35,12 +36,10 @@ impl Orientation {
     };
 
     fn new(facing: Direction, up: Direction) -> Result<Self, String> {
-        let same_axis = match (&facing, &up) {
-            (Direction::X(_), Direction::X(_)) => true,
-            (Direction::Y(_), Direction::Y(_)) => true,
-            (Direction::Z(_), Direction::Z(_)) => true,
-            _ => false
-        };
+        let same_axis = matches!((&facing, &up),
+            (Direction::X(_), Direction::X(_))
+            | (Direction::Y(_), Direction::Y(_))
+            | (Direction::Z(_), Direction::Z(_)));
         if same_axis {
             Err(format!("Cannot have orientation with facing ({}) and up ({}) on the same axis", facing, up))
         } else {
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use aoc_common::Rng;
+use crate::MINIMUM_OVERLAP;
+
+pub const DEFAULT_SIZE: usize = 5;
+
+/// How far a scanner can detect beacons along each axis.
+const RANGE: i64 = 1000;
+
+type Vector = [i64; 3];
+
+/// Reports from `size` scanners, derived from a hidden map of beacons. Each scanner after the first is
+/// placed near an earlier one, with enough beacons in both their ranges for them to be matched up, and
+/// reports every beacon in its range relative to itself, facing one of the 24 ways it could be turned.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut scanners: Vec<Vector> = vec![[0, 0, 0]];
+    let mut beacons: HashSet<Vector> = HashSet::new();
+    scatter(&mut rng, &mut beacons, [-RANGE; 3], [RANGE; 3], 10);
+    while scanners.len() < size.max(1) {
+        let near = *rng.choose(&scanners);
+        let scanner: Vector = near.map(|n| n + rng.between(-RANGE - 200, RANGE + 200));
+        let min: Vector = [0, 1, 2].map(|a| (scanner[a] - RANGE).max(near[a] - RANGE));
+        let max: Vector = [0, 1, 2].map(|a| (scanner[a] + RANGE).min(near[a] + RANGE));
+        scatter(&mut rng, &mut beacons, min, max, MINIMUM_OVERLAP + 2);
+        scatter(&mut rng, &mut beacons, scanner.map(|s| s - RANGE), scanner.map(|s| s + RANGE), 10);
+        scanners.push(scanner);
+    }
+    let rotations = rotations();
+    let mut sections = Vec::new();
+    for (i, scanner) in scanners.iter().enumerate() {
+        let rotation = if i == 0 { rotations[0] } else { *rng.choose(&rotations) };
+        let mut lines = vec![format!("--- scanner {} ---", i)];
+        let mut visible: Vec<&Vector> = beacons.iter().filter(|b| (0..3).all(|a| (b[a] - scanner[a]).abs() <= RANGE)).collect();
+        visible.sort();
+        rng.shuffle(&mut visible);
+        for beacon in visible {
+            let relative = [0, 1, 2].map(|a| beacon[a] - scanner[a]);
+            let turned = rotation.map(|(axis, sign)| relative[axis] * sign);
+            lines.push(format!("{},{},{}", turned[0], turned[1], turned[2]));
+        }
+        sections.push(lines.join("\n"));
+    }
+    sections.join("\n\n")
+}
+
+/// Adds some new beacons within a box.
+fn scatter(rng: &mut Rng, beacons: &mut HashSet<Vector>, min: Vector, max: Vector, count: usize) {
+    let mut added = 0;
+    while added < count {
+        if beacons.insert([0, 1, 2].map(|a| rng.between(min[a], max[a]))) {
+            added += 1;
+        }
+    }
+}
+
+/// The 24 ways of turning a scanner, as the axis and sign each new axis takes from the old: every
+/// permutation of the axes with signs giving a rotation rather than a reflection.
+fn rotations() -> Vec<[(usize, i64); 3]> {
+    let mut rotations = Vec::new();
+    for (permutation, parity) in [([0, 1, 2], 1), ([1, 2, 0], 1), ([2, 0, 1], 1), ([0, 2, 1], -1), ([2, 1, 0], -1), ([1, 0, 2], -1)] {
+        for signs in 0..8 {
+            let sign = |a: usize| if signs & (1 << a) == 0 { 1 } else { -1 };
+            if sign(0) * sign(1) * sign(2) == parity {
+                rotations.push([0, 1, 2].map(|a| (permutation[a], sign(a))));
+            }
+        }
+    }
+    rotations
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+use point::Point;
+use std::collections::HashSet;
+use aoc_common::{Answer, Diagnostic};
+use aoc_common::input;
+use crate::frame::Orientation;
+use crate::frame::FrameOfReference;
+
+pub use crate::scanner::Scanner;
+
+mod scanner;
+mod point;
+mod frame;
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+const MINIMUM_OVERLAP: usize = 12;
+
+/// Scanners located in the frame of reference of the first.
+pub type Input = Vec<Scanner>;
+
+/// Parses and locates every scanner. Both parts need every scanner located, and input where some
+/// can't be is invalid, so the search happens here rather than in either part.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    locate_scanners(parse_scanners(text)?)
+}
+
+/// Checks every scanner's report without locating them, listing each header which isn't a scanner's
+/// name and each beacon which isn't three numbers.
+pub fn validate(text: &str) -> Vec<Error> {
+    let sections = input::numbered_sections(text);
+    if sections.is_empty() {
+        return vec![Error::NoScanners];
+    }
+    let mut errors = Vec::new();
+    for (offset, section) in sections {
+        let (header, beacons) = section.split_once('\n').unwrap_or((section, ""));
+        errors.extend(input::parse_section::<Scanner, Error>((offset, header)).err());
+        errors.extend(input::validate_lines::<Point, Error>(beacons).into_iter().map(|e| e.offset_lines(offset + 1)));
+    }
+    errors
+}
+
+pub fn part1(found: &Input) -> Answer {
+    count_beacons(found).into()
+}
+
+pub fn part2(found: &Input) -> Answer {
+    max_distance(found).into()
+}
+
+pub fn parse_scanners(text: &str) -> Result<Vec<Scanner>, Error> {
+    input::numbered_sections(text).into_iter().map(input::parse_section).collect()
+}
+
+/// Places every scanner in the frame of reference of the first, by repeatedly matching an unknown
+/// scanner against one already found.
+pub fn locate_scanners(scanners: Vec<Scanner>) -> Result<Vec<Scanner>, Error> {
+    let mut scanners = scanners.into_iter();
+    let mut reference_scanner: Scanner = scanners.next().ok_or(Error::NoScanners)?;
+    reference_scanner.frame = Some(FrameOfReference::BASE);
+    let mut found: Vec<Scanner> = vec![reference_scanner];
+    let mut remaining: Vec<Scanner> = scanners.collect();
+    while !remaining.is_empty() {
+        let mut matched = None;
+        for source in &found {
+            for i in 0..remaining.len() {
+                if let Some(frame) = remaining[i].find_frame(source, MINIMUM_OVERLAP) {
+                    let mut newly_found = remaining.remove(i);
+                    newly_found.frame = Some(frame);
+                    matched = Some(newly_found);
+                    break;
+                }
+            }
+            if matched.is_some() {
+                break;
+            }
+        }
+        if let Some(newly_found) = matched {
+            found.push(newly_found);
+        } else {
+            return Err(Error::UnmatchedScanners { remaining: remaining.len() });
+        }
+    }
+    Ok(found)
+}
+
+pub fn count_beacons(found: &[Scanner]) -> usize {
+    let absolute_beacons: HashSet<Point> = found.iter().flat_map(|s| s.absolute_beacons()).collect();
+    absolute_beacons.len()
+}
+
+pub fn max_distance(found: &[Scanner]) -> usize {
+    let mut max_distance = 0;
+    for a in 0..found.len() {
+        for b in 0..found.len() {
+            if a != b {
+                let distance = found[a].frame.as_ref().unwrap().position.manhatten_distance(&found[b].frame.as_ref().unwrap().position);
+                if distance > max_distance {
+                    max_distance = distance;
+                }
+            }
+        }
+    }
+    max_distance
+}
\ No newline at end of file
@@ -5,6 +5,9 @@ use std::str::FromStr;
 use crate::frame::FrameOfReference;
 use crate::Orientation;
 use crate::frame::Direction;
+use crate::Error;
+use aoc_common::Span;
+use aoc_common::input::Validate;
 
 #[derive(Hash, Eq, PartialEq, Copy, Clone)]
 pub struct Point {
@@ -110,10 +113,11 @@ impl Point {
 }
 
 impl FromStr for Point {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let coordinates: Vec<isize> = line.split(",").map(|c| c.parse().unwrap()).collect();
+        let coordinates: Vec<isize> = line.split(",").map(|c| c.parse()
+            .map_err(|_| Error::InvalidBeacon(Span::line(line)))).collect::<Result<_, _>>()?;
         if coordinates.len() == 3 {
             Ok(Point {
                 x: coordinates[0],
@@ -121,13 +125,15 @@ impl FromStr for Point {
                 z: coordinates[2]
             })
         } else {
-            Err(format!("Expected 3 coordinates: {}", line))
+            Err(Error::InvalidBeacon(Span::line(line)))
         }
     }
 }
 
+impl Validate for Point {}
+
 impl Display for Point {
     fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "({},{},{})", self.x, self.y, self.z)
+        write!(f, "{},{},{}", self.x, self.y, self.z)
     }
 }
\ No newline at end of file
@@ -3,10 +3,14 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::str::FromStr;
 use std::collections::HashSet;
+use aoc_common::{Diagnostic, Span};
+use aoc_common::input;
 use crate::Point;
 use crate::FrameOfReference;
 use crate::Orientation;
+use crate::Error;
 
+#[derive(PartialEq)]
 pub struct Scanner {
     name: String,
     pub frame: Option<FrameOfReference>,
@@ -61,12 +65,16 @@ impl Scanner {
 }
 
 impl FromStr for Scanner {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let mut iter = text.lines();
-        let name = iter.next().unwrap().to_string();
-        let beacons: Vec<Point> = iter.map(|p| p.parse().unwrap()).collect();
+        let mut iter = input::lines(text).enumerate();
+        let name = match iter.next() {
+            Some((_, header)) if header.starts_with("--- scanner") && header.ends_with("---") => header.to_string(),
+            Some((_, header)) => return Err(Error::InvalidHeader(Span::line(header))),
+            None => return Err(Error::InvalidHeader(Span::line("")))
+        };
+        let beacons: Vec<Point> = iter.map(|(i, p)| p.parse().map_err(|e: Error| e.offset_lines(i))).collect::<Result<_, _>>()?;
         Ok(Self {
             name,
             frame: None,
@@ -75,8 +83,13 @@ impl FromStr for Scanner {
     }
 }
 
+/// Writes the scanner's report in the same format that `parse` reads: its name, then one beacon per line.
 impl Display for Scanner {
     fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.name)
+        write!(f, "{}", self.name)?;
+        for beacon in &self.beacons {
+            write!(f, "\n{}", beacon)?;
+        }
+        Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use super::*;
+use aoc_common::{Rng, assert_round_trip};
+
+#[test]
+fn point_round_trip() {
+    assert_round_trip(200, |seed| {
+        let mut rng = Rng::new(seed);
+        let mut coordinate = || rng.between(-5000, 5000) as isize;
+        Point { x: coordinate(), y: coordinate(), z: coordinate() }
+    });
+}
+
+#[test]
+fn scanner_round_trip() {
+    let scanners = parse_scanners(&generate::generate(0, 3)).unwrap();
+    assert_round_trip(scanners.len() as u64, |i| {
+        let scanner = &scanners[i as usize];
+        scanner.to_string().parse::<Scanner>().unwrap()
+    });
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't a direction and distance separated by a space
+    InvalidInstruction(Span),
+    /// A direction other than forward, up or down
+    InvalidDirection(Span),
+    /// A distance which isn't a whole number
+    InvalidDistance(Span),
+    /// An instruction taking the submarine above the surface, in strict mode
+    SurfaceBreached { span: Span, model: String, depth: i64 },
+    /// An instruction taking the submarine below the maximum depth, in strict mode
+    TooDeep { span: Span, model: String, depth: i64, max_depth: i64 }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidInstruction(span) => write!(f, "expected `<direction> <distance>`, found `{}`", span.text),
+            Error::InvalidDirection(span) => write!(f, "expected `forward`, `up` or `down`, found `{}`", span.text),
+            Error::InvalidDistance(span) => write!(f, "expected a distance, found `{}`", span.text),
+            Error::SurfaceBreached { model, depth, .. } => write!(f, "the {} model breaches the surface, rising to depth {}", model, depth),
+            Error::TooDeep { model, depth, max_depth, .. } => write!(f, "the {} model dives to depth {}, below the maximum depth of {}", model, depth, max_depth)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidInstruction(span) | Error::InvalidDirection(span) | Error::InvalidDistance(span) => Some(span),
+            Error::SurfaceBreached { span, .. } | Error::TooDeep { span, .. } => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidInstruction(span) | Error::InvalidDirection(span) | Error::InvalidDistance(span) => Some(span),
+            Error::SurfaceBreached { span, .. } | Error::TooDeep { span, .. } => Some(span)
+        }
+    }
+}
\ No newline at end of file
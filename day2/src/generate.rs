@@ -0,0 +1,26 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` commands, never going up past the surface, so that neither the depth nor the aim goes negative.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut depth = 0;
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let distance = rng.between(1, 9);
+        let direction = match rng.below(3) {
+            0 => "forward",
+            1 => "down",
+            _ if depth >= distance => "up",
+            _ => "down"
+        };
+        match direction {
+            "down" => depth += distance,
+            "up" => depth -= distance,
+            _ => ()
+        }
+        lines.push(format!("{} {}", direction, distance));
+    }
+    lines.join("\n")
+}
\ No newline at end of file
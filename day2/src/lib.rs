@@ -0,0 +1,124 @@
+use std::str::FromStr;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+mod model;
+mod trajectory;
+
+pub use error::Error;
+pub use model::{Limit, Models, SubmarineModel, Violation, follow, process, side_by_side};
+pub use trajectory::{Point, Trace, svg, to_csv, trace};
+
+#[cfg(test)]
+mod tests;
+
+pub struct Instruction {
+    direction: Direction,
+    distance: u32
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub horizontal: i64,
+    pub depth: i64
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LocationWithAim {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward, Down, Up
+}
+
+pub type Input = Vec<Instruction>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing each direction and distance which isn't valid.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Instruction, Error>(text)
+}
+
+pub fn part1(instructions: &Input) -> Answer {
+    let location = process_instructions(instructions);
+    (location.horizontal * location.depth).into()
+}
+
+pub fn part2(instructions: &Input) -> Answer {
+    let location = process_instructions_with_aim(instructions);
+    (location.horizontal * location.depth).into()
+}
+
+impl Instruction {
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn distance(&self) -> u32 {
+        self.distance
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = Self::words(line)?;
+        Ok(Instruction {
+            direction: Self::parse_direction(line, direction)?,
+            distance: Self::parse_distance(line, distance)?
+        })
+    }
+}
+
+impl Validate for Instruction {
+    fn problems(line: &str) -> Vec<Error> {
+        match Self::words(line) {
+            Ok((direction, distance)) => [Self::parse_direction(line, direction).err(), Self::parse_distance(line, distance).err()].into_iter().flatten().collect(),
+            Err(e) => vec![e]
+        }
+    }
+}
+
+impl Instruction {
+    fn words(line: &str) -> Result<(&str, &str), Error> {
+        let words: Vec<&str> = line.split(" ").collect();
+        match words[..] {
+            [direction, distance] => Ok((direction, distance)),
+            _ => Err(Error::InvalidInstruction(Span::line(line)))
+        }
+    }
+
+    fn parse_direction(line: &str, word: &str) -> Result<Direction, Error> {
+        match word {
+            "forward" => Ok(Direction::Forward),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            _ => Err(Error::InvalidDirection(Span::new(line, word)))
+        }
+    }
+
+    fn parse_distance(line: &str, word: &str) -> Result<u32, Error> {
+        word.parse().map_err(|_| Error::InvalidDistance(Span::new(line, word)))
+    }
+}
+
+/// Moves a submarine following the simple model, with up and down changing the depth directly.
+pub fn process_instructions(instructions: &[Instruction]) -> Location {
+    process(instructions)
+}
+
+/// Moves a submarine following the aim model, with up and down turning it instead.
+pub fn process_instructions_with_aim(instructions: &[Instruction]) -> LocationWithAim {
+    process(instructions)
+}
\ No newline at end of file
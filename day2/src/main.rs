@@ -1,87 +1,45 @@
-use std::str::FromStr;
-use std::env;
 use std::fs;
-
-struct Instruction {
-    direction: Direction,
-    distance: u32
-}
-
-struct Location {
-    horizontal: u32,
-    depth: u32
-}
-
-struct LocationWithAim {
-    horizontal: u32,
-    depth: u32,
-    aim: i32
-}
-
-enum Direction {
-    Forward, Down, Up
-}
+use day2::{follow, parse, part1, part2, side_by_side, svg, to_csv, Limit, Models, Trace};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let instructions: Vec<Instruction> = text.split("\r\n").map(|s| s.parse()
-            .expect(&format!("Error parsing instruction {}", s))).collect();
-        let location = process_instructions(&instructions);
-        println!("Horizontal {}, Depth {}, Multiply {}", location.horizontal, location.depth, location.horizontal * location.depth);
-        let with_aim = process_instructions_with_aim(&instructions);
-        println!("(with aim) Horizontal {}, Depth {}, Multiply {}", with_aim.horizontal, with_aim.depth, with_aim.horizontal * with_aim.depth);
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
-}
-
-impl FromStr for Instruction {
-    type Err = String;
-
-    fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let words: Vec<&str> = line.split(" ").collect();
-        if words.len() != 2 {
-            Err(format!("Must be 2 words: {}", line))
-        } else {
-            let direction: Direction = match words[0] {
-                "forward" => Ok(Direction::Forward),
-                "up" => Ok(Direction::Up),
-                "down" => Ok(Direction::Down),
-                _ => Err(format!("Invalid direction: {}", words[0]))
-            }.unwrap();
-            let distance: u32 = words[1].parse().unwrap();
-            Ok(Instruction { direction, distance })
-        }
-    }
-}
-
-fn process_instructions(instructions: &Vec<Instruction>) -> Location {
-    let mut location = Location { horizontal: 0, depth: 0 };
-    for instruction in instructions.iter() {
-        match instruction.direction {
-            Direction::Down => location.depth += instruction.distance,
-            Direction::Up => location.depth -= instruction.distance,
-            Direction::Forward => location.horizontal += instruction.distance,
+    aoc_common::run(2, &[], &["models", "max-depth", "strict", "csv", "svg"], |text, args, report| {
+        let instructions = report.parse(|| parse(text))?;
+        let Models(mut models) = args.option("models")?.unwrap_or(Models::all());
+        let max_depth = args.option("max-depth")?;
+        let strict = args.flag("strict");
+        let csv: Option<String> = args.option("csv")?;
+        let plot: Option<String> = args.option("svg")?;
+        report.part(1, || part1(&instructions));
+        report.part(2, || part2(&instructions));
+        let tracing = csv.is_some() || plot.is_some();
+        let mut traces = Vec::new();
+        for model in &mut models {
+            let name = model.name();
+            let mut points = Vec::new();
+            let violations = follow(model.as_mut(), &instructions, max_depth, strict, tracing.then_some(&mut points)).map_err(|v| v.error(name, text))?;
+            if tracing {
+                traces.push(Trace { model: name, points });
+            }
+            let indices = |limit: fn(&Limit) -> bool| violations.iter().filter(|v| limit(&v.limit)).map(|v| v.index.to_string()).collect::<Vec<String>>();
+            for (field, value) in model.state() {
+                report.value(&format!("{}_{}", name, field), value);
+            }
+            let breaches = indices(|l| *l == Limit::Surface);
+            if !breaches.is_empty() {
+                report.value(&format!("{}_surface_breaches", name), breaches.join(","));
+            }
+            let too_deep = indices(|l| matches!(l, Limit::MaxDepth(_)));
+            if !too_deep.is_empty() {
+                report.value(&format!("{}_too_deep", name), too_deep.join(","));
+            }
         }
-    }
-    location
-}
-
-fn process_instructions_with_aim(instructions: &Vec<Instruction>) -> LocationWithAim {
-    let mut location = LocationWithAim { horizontal: 0, depth: 0, aim: 0 };
-    for instruction in instructions.iter() {
-        match instruction.direction {
-            Direction::Down => location.aim += instruction.distance as i32,
-            Direction::Up => location.aim -= instruction.distance as i32,
-            Direction::Forward => {
-                location.horizontal += instruction.distance;
-                location.depth = (location.depth as i32 + instruction.distance as i32 * location.aim) as u32;
+        report.value("final_states", format!("\n{}", side_by_side(&models)));
+        // Only written once every model has kept within the limits, so strict mode leaves no traces behind
+        for (path, contents) in [(csv, to_csv(&traces)), (plot, svg(&traces))] {
+            if let Some(path) = path {
+                fs::write(&path, contents).map_err(|e| format!("Error writing to {}: {}", path, e))?;
             }
         }
-    }
-    location
+        Ok(())
+    });
 }
\ No newline at end of file
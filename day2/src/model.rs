@@ -0,0 +1,219 @@
+use std::str::FromStr;
+use aoc_common::Span;
+use aoc_common::input;
+use crate::{Direction, Error, Instruction, Location, LocationWithAim, Point};
+
+/// How a submarine moves in response to each instruction. Each model is its own state, starting from
+/// the surface, so several models can follow the same course side by side.
+pub trait SubmarineModel {
+    fn name(&self) -> &'static str;
+
+    fn apply(&mut self, instruction: &Instruction);
+
+    fn horizontal(&self) -> i64;
+
+    /// How far below the surface the submarine is, which is negative once it has breached the surface.
+    fn depth(&self) -> i64;
+
+    /// Which way the submarine is pointing, for models which have an aim.
+    fn aim(&self) -> i64 {
+        0
+    }
+
+    /// Every part of the state worth showing, by name.
+    fn state(&self) -> Vec<(&'static str, i64)> {
+        vec![("horizontal", self.horizontal()), ("depth", self.depth())]
+    }
+}
+
+/// A limit on the submarine's depth: the surface, or a maximum depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Surface,
+    MaxDepth(i64)
+}
+
+/// An instruction, by its index in the course, which took the submarine past a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub limit: Limit,
+    pub depth: i64
+}
+
+/// Models chosen on the command line by name, as a comma separated list such as `simple,aim`, or `all`.
+pub struct Models(pub Vec<Box<dyn SubmarineModel>>);
+
+/// Follows a course from the surface with a model.
+pub fn process<M: SubmarineModel + Default>(instructions: &[Instruction]) -> M {
+    let mut model = M::default();
+    for instruction in instructions {
+        model.apply(instruction);
+    }
+    model
+}
+
+/// Follows a course with a model, listing each instruction which takes the submarine from within its
+/// limits to above the surface or below `max_depth`. Staying past a limit isn't counted again until the
+/// submarine has come back within it. In strict mode the first violation is returned as an error instead.
+/// Given somewhere to record them, the model's state at every step is recorded along the way.
+pub fn follow(model: &mut dyn SubmarineModel, instructions: &[Instruction], max_depth: Option<i64>, strict: bool, mut points: Option<&mut Vec<Point>>) -> Result<Vec<Violation>, Violation> {
+    let limit = |depth: i64| match max_depth {
+        _ if depth < 0 => Some(Limit::Surface),
+        Some(max_depth) if depth > max_depth => Some(Limit::MaxDepth(max_depth)),
+        _ => None
+    };
+    let mut violations = Vec::new();
+    let mut previous = limit(model.depth());
+    if let Some(points) = points.as_deref_mut() {
+        points.push(Point::of(model));
+    }
+    for (index, instruction) in instructions.iter().enumerate() {
+        model.apply(instruction);
+        if let Some(points) = points.as_deref_mut() {
+            points.push(Point::of(model));
+        }
+        let depth = model.depth();
+        let current = limit(depth);
+        if let Some(limit) = current.filter(|_| current != previous) {
+            let violation = Violation { index, limit, depth };
+            if strict {
+                return Err(violation);
+            }
+            violations.push(violation);
+        }
+        previous = current;
+    }
+    Ok(violations)
+}
+
+impl Violation {
+    /// The error for a model going past a limit, pointing at the instruction's line of the input.
+    pub fn error(&self, model: &str, text: &str) -> Error {
+        let line = input::lines(text).nth(self.index).unwrap_or_default();
+        let span = Span::line(line).on_line(self.index + 1);
+        let model = model.to_string();
+        match self.limit {
+            Limit::Surface => Error::SurfaceBreached { span, model, depth: self.depth },
+            Limit::MaxDepth(max_depth) => Error::TooDeep { span, model, depth: self.depth, max_depth }
+        }
+    }
+}
+
+impl SubmarineModel for Location {
+    fn name(&self) -> &'static str {
+        "simple"
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        match instruction.direction {
+            Direction::Down => self.depth += instruction.distance as i64,
+            Direction::Up => self.depth -= instruction.distance as i64,
+            Direction::Forward => self.horizontal += instruction.distance as i64
+        }
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+}
+
+impl SubmarineModel for LocationWithAim {
+    fn name(&self) -> &'static str {
+        "aim"
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        match instruction.direction {
+            Direction::Down => self.aim += instruction.distance as i64,
+            Direction::Up => self.aim -= instruction.distance as i64,
+            Direction::Forward => {
+                self.horizontal += instruction.distance as i64;
+                self.depth += instruction.distance as i64 * self.aim;
+            }
+        }
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn aim(&self) -> i64 {
+        self.aim
+    }
+
+    fn state(&self) -> Vec<(&'static str, i64)> {
+        vec![("horizontal", self.horizontal), ("depth", self.depth), ("aim", self.aim)]
+    }
+}
+
+impl Models {
+    pub const NAMES: [&'static str; 2] = ["simple", "aim"];
+
+    /// A model at the surface, by name.
+    pub fn model(name: &str) -> Option<Box<dyn SubmarineModel>> {
+        match name {
+            "simple" => Some(Box::new(Location::default())),
+            "aim" => Some(Box::new(LocationWithAim::default())),
+            _ => None
+        }
+    }
+
+    /// Every model, at the surface.
+    pub fn all() -> Self {
+        Models(Self::NAMES.iter().filter_map(|n| Self::model(n)).collect())
+    }
+
+    pub fn apply(&mut self, instructions: &[Instruction]) {
+        for instruction in instructions {
+            for model in &mut self.0 {
+                model.apply(instruction);
+            }
+        }
+    }
+}
+
+impl FromStr for Models {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text == "all" {
+            return Ok(Self::all());
+        }
+        text.split(",").map(|n| n.trim()).map(|n| Self::model(n).ok_or(format!("Unknown model: {}, expected one of {} or all", n, Self::NAMES.join(", "))))
+            .collect::<Result<_, _>>().map(Models)
+    }
+}
+
+/// A table of each model's state, one column per model. Parts of the state a model doesn't have are
+/// shown as `-`.
+pub fn side_by_side(models: &[Box<dyn SubmarineModel>]) -> String {
+    let states: Vec<Vec<(&'static str, i64)>> = models.iter().map(|m| m.state()).collect();
+    let mut fields: Vec<&'static str> = Vec::new();
+    for (field, _) in states.iter().flatten() {
+        if !fields.contains(field) {
+            fields.push(field);
+        }
+    }
+    let mut rows = vec![std::iter::once(String::new()).chain(models.iter().map(|m| m.name().to_string())).collect::<Vec<String>>()];
+    for field in fields {
+        let values = states.iter().map(|s| s.iter().find(|(f, _)| *f == field).map(|(_, v)| v.to_string()).unwrap_or("-".to_string()));
+        rows.push(std::iter::once(field.to_string()).chain(values).collect());
+    }
+    let widths: Vec<usize> = (0..rows[0].len()).map(|c| rows.iter().map(|r| r[c].len()).max().unwrap_or(0)).collect();
+    rows.iter().map(|row| {
+        let cells: Vec<String> = row.iter().zip(&widths).enumerate().map(|(c, (cell, &width))| match c {
+            0 => format!("{:<width$}", cell, width = width),
+            _ => format!("{:>width$}", cell, width = width)
+        }).collect();
+        cells.join("  ")
+    }).collect::<Vec<String>>().join("\n")
+}
\ No newline at end of file
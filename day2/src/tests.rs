@@ -0,0 +1,48 @@
+use super::*;
+
+const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+#[test]
+fn models_follow_the_example_course() {
+    let instructions = parse(EXAMPLE).unwrap();
+    assert_eq!(part1(&instructions), Answer::from(150));
+    assert_eq!(part2(&instructions), Answer::from(900));
+    assert_eq!(process_instructions(&instructions), Location { horizontal: 15, depth: 10 });
+    assert_eq!(process_instructions_with_aim(&instructions), LocationWithAim { horizontal: 15, depth: 60, aim: 10 });
+    let mut models: Models = "all".parse().unwrap();
+    models.apply(&instructions);
+    assert_eq!(models.0[0].state(), vec![("horizontal", 15), ("depth", 10)]);
+    assert_eq!(models.0[1].state(), vec![("horizontal", 15), ("depth", 60), ("aim", 10)]);
+    assert!("simple,sideways".parse::<Models>().is_err());
+}
+
+#[test]
+fn follow_reports_each_violation() {
+    let instructions = parse("forward 5\nup 3\ndown 8\nforward 2\nup 9\ndown 4").unwrap();
+    let mut simple = Location::default();
+    let violations = follow(&mut simple, &instructions, None, false, None).unwrap();
+    assert_eq!(violations, vec![Violation { index: 1, limit: Limit::Surface, depth: -3 }, Violation { index: 4, limit: Limit::Surface, depth: -4 }]);
+    assert_eq!(simple, Location { horizontal: 7, depth: 0 });
+    let mut aim = LocationWithAim::default();
+    let violation = follow(&mut aim, &instructions, Some(6), true, None).unwrap_err();
+    assert_eq!(violation, Violation { index: 3, limit: Limit::MaxDepth(6), depth: 10 });
+    assert!(matches!(violation.error("aim", "forward 5\nup 3\ndown 8\nforward 2"), Error::TooDeep { span, .. } if span.line == 4 && span.text == "forward 2"));
+}
+
+#[test]
+fn trace_records_every_step() {
+    let instructions = parse("forward 5\ndown 5\nforward 8").unwrap();
+    let traces = [trace(&mut LocationWithAim::default(), &instructions)];
+    assert_eq!(traces[0].points.len(), 4);
+    assert_eq!(traces[0].points[3], Point { horizontal: 13, depth: 40, aim: 5 });
+    assert!(to_csv(&traces).ends_with("aim,2,5,0,5\naim,3,13,40,5\n"));
+    assert_eq!(svg(&traces).matches("<polyline").count(), 1);
+}
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("forward 5\nsideways x\nup\ndown 2");
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], Error::InvalidDirection(span) if span.line == 2 && span.text == "sideways"));
+    assert!(matches!(&errors[1], Error::InvalidDistance(span) if span.line == 2 && span.column == 10));
+    assert!(matches!(&errors[2], Error::InvalidInstruction(span) if span.line == 3));
+}
@@ -0,0 +1,72 @@
+use crate::{Instruction, SubmarineModel, follow};
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 400.0;
+const MARGIN: f64 = 20.0;
+const COLOURS: [&str; 6] = ["#1f77b4", "#d62728", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b"];
+
+/// The state of a submarine at one point on its course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64
+}
+
+/// The course a model took: where it started, then its state after each instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub model: &'static str,
+    pub points: Vec<Point>
+}
+
+impl Point {
+    /// The current state of a model.
+    pub fn of(model: &dyn SubmarineModel) -> Self {
+        Point { horizontal: model.horizontal(), depth: model.depth(), aim: model.aim() }
+    }
+}
+
+/// Follows a course with a model, recording its state at every step.
+pub fn trace(model: &mut dyn SubmarineModel, instructions: &[Instruction]) -> Trace {
+    let mut points = Vec::with_capacity(instructions.len() + 1);
+    // Without a maximum depth or strict mode, following the course can't fail
+    let _ = follow(model, instructions, None, false, Some(&mut points));
+    Trace { model: model.name(), points }
+}
+
+/// Every step of every trace as CSV, numbering the steps from 0 for the starting point.
+pub fn to_csv(traces: &[Trace]) -> String {
+    let mut csv = "model,step,horizontal,depth,aim\n".to_string();
+    for trace in traces {
+        for (step, p) in trace.points.iter().enumerate() {
+            csv += &format!("{},{},{},{},{}\n", trace.model, step, p.horizontal, p.depth, p.aim);
+        }
+    }
+    csv
+}
+
+/// An SVG plot of depth against horizontal distance, with a line for each trace and the surface drawn
+/// across the top. Both axes are scaled to fit every trace, so courses with very different depths are
+/// still visible but their slopes aren't to scale.
+pub fn svg(traces: &[Trace]) -> String {
+    let points = || traces.iter().flat_map(|t| &t.points);
+    let max_horizontal = points().map(|p| p.horizontal).max().unwrap_or(0).max(1);
+    let min_depth = points().map(|p| p.depth).min().unwrap_or(0).min(0);
+    let max_depth = points().map(|p| p.depth).max().unwrap_or(0).max(min_depth + 1);
+    let x = |h: i64| MARGIN + h as f64 / max_horizontal as f64 * (WIDTH - 2.0 * MARGIN);
+    let y = |d: i64| MARGIN + (d - min_depth) as f64 / (max_depth - min_depth) as f64 * (HEIGHT - 2.0 * MARGIN);
+    let mut lines = vec![
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">", WIDTH, HEIGHT, WIDTH, HEIGHT),
+        format!("<rect width=\"{}\" height=\"{}\" fill=\"white\"/>", WIDTH, HEIGHT),
+        format!("<line x1=\"{}\" y1=\"{:.1}\" x2=\"{}\" y2=\"{:.1}\" stroke=\"#aaaaaa\" stroke-dasharray=\"4\"/>", MARGIN, y(0), WIDTH - MARGIN, y(0))
+    ];
+    for (i, trace) in traces.iter().enumerate() {
+        let colour = COLOURS[i % COLOURS.len()];
+        let points: Vec<String> = trace.points.iter().map(|p| format!("{:.1},{:.1}", x(p.horizontal), y(p.depth))).collect();
+        lines.push(format!("<polyline points=\"{}\" fill=\"none\" stroke=\"{}\"/>", points.join(" "), colour));
+        lines.push(format!("<text x=\"{}\" y=\"{}\" fill=\"{}\" font-family=\"sans-serif\" font-size=\"12\">{}</text>", WIDTH - MARGIN - 60.0, MARGIN + 14.0 * (i + 1) as f64, colour, trace.model));
+    }
+    lines.push("</svg>".to_string());
+    lines.join("\n")
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, GridError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A pixel which is neither light nor dark
+    InvalidPixel(Span),
+    /// An enhancement algorithm which isn't exactly 512 pixels long
+    InvalidAlgorithmLength { span: Span, found: usize },
+    /// An image row with a different number of pixels to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// Input without an algorithm and image separated by a blank line
+    MissingImage
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidPixel(span) => write!(f, "expected `#` or `.`, found `{}`", span.text),
+            Error::InvalidAlgorithmLength { found, .. } => write!(f, "expected 512 pixels in the enhancement algorithm, found {}", found),
+            Error::InvalidRowLength { span, expected } => write!(f, "expected {} pixels in image row, found `{}`", expected, span.text),
+            Error::MissingImage => write!(f, "expected an enhancement algorithm and image separated by a blank line")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidPixel(span) => Some(span),
+            Error::InvalidAlgorithmLength { span, .. } => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidPixel(span) => Some(span),
+            Error::InvalidAlgorithmLength { span, .. } => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
+
+impl From<GridError> for Error {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::InvalidCell(span) => Error::InvalidPixel(span),
+            GridError::InvalidRowLength { span, expected } => Error::InvalidRowLength { span, expected },
+            GridError::Empty => Error::MissingImage
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use aoc_common::{Grid, Rng};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A random enhancement algorithm and a `size` by `size` image. An algorithm lighting the infinite dark
+/// background always darkens it again on the next step, so that the lit pixels stay countable.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let pixel = |lit: bool| if lit { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng.chance(0.5))).collect();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let image = Grid::from_fn(size.max(1), size.max(1), |_| pixel(rng.chance(0.5)));
+    format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), image)
+}
\ No newline at end of file
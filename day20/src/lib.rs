@@ -0,0 +1,134 @@
+use std::str::FromStr;
+use aoc_common::{Answer, Diagnostic, Grid, Position, Span};
+use aoc_common::input;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+pub struct Puzzle {
+    pub enhancer: Enhancer,
+    pub image: Image
+}
+
+#[derive(Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    edge: bool
+}
+
+pub struct Enhancer {
+    data: [bool; 512]
+}
+
+pub type Input = Puzzle;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let mut iter = input::numbered_sections(text).into_iter();
+    let enhancer: Enhancer = input::parse_section(iter.next().ok_or(Error::MissingImage)?)?;
+    let image: Image = input::parse_section(iter.next().ok_or(Error::MissingImage)?)?;
+    Ok(Puzzle { enhancer, image })
+}
+
+/// Checks the enhancement algorithm and image without enhancing, listing every pixel which is neither
+/// light nor dark, an algorithm of the wrong length and every image row of the wrong length.
+pub fn validate(text: &str) -> Vec<Error> {
+    let sections = input::numbered_sections(text);
+    if sections.len() < 2 {
+        return vec![Error::MissingImage];
+    }
+    let (_, algorithm) = sections[0];
+    let mut errors: Vec<Error> = algorithm.chars().enumerate().filter(|(_, c)| parse_pixel(*c).is_none())
+        .map(|(i, _)| Error::InvalidPixel(Span::char_at(algorithm, i).on_line(1))).collect();
+    let found = algorithm.chars().count();
+    if found != 512 {
+        errors.push(Error::InvalidAlgorithmLength { span: Span::line(algorithm).on_line(1), found });
+    }
+    let (offset, image) = sections[1];
+    errors.extend(Grid::validate(image, parse_pixel).into_iter().map(|e| Error::from(e).offset_lines(offset)));
+    errors
+}
+
+pub fn part1(puzzle: &Input) -> Answer {
+    puzzle.enhancer.enhance_times(&puzzle.image, 2).lit_pixels().into()
+}
+
+pub fn part2(puzzle: &Input) -> Answer {
+    puzzle.enhancer.enhance_times(&puzzle.image, 50).lit_pixels().into()
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    }
+}
+
+impl FromStr for Enhancer {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let data: Vec<bool> = line.chars().enumerate().map(|(i, c)| parse_pixel(c)
+            .ok_or_else(|| Error::InvalidPixel(Span::char_at(line, i)))).collect::<Result<_, _>>()?;
+        let found = data.len();
+        let data: [bool; 512] = data.try_into().map_err(|_| Error::InvalidAlgorithmLength { span: Span::line(line), found })?;
+        Ok(Self { data })
+    }
+}
+
+impl FromStr for Image {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            pixels: Grid::parse(text, parse_pixel)?,
+            edge: false
+        })
+    }
+}
+
+impl Image {
+    pub fn lit_pixels(&self) -> usize {
+        self.pixels.iter().filter(|(_, &v)| v).count()
+    }
+
+    /// The pixel a number of columns and rows away from a position, which may be out in the infinite
+    /// edge of the image.
+    fn get(&self, position: Position, dx: isize, dy: isize) -> bool {
+        match self.pixels.offset(position, dx, dy) {
+            Some(p) => self.pixels[p],
+            None => self.edge
+        }
+    }
+}
+
+impl Enhancer {
+    pub fn enhance_times(&self, image: &Image, cycles: usize) -> Image {
+        let mut image = image.clone();
+        for _ in 0..cycles {
+            image = self.enhance(&image);
+        }
+        image
+    }
+
+    /// Enhances an image, growing it by one pixel on every side. Each new pixel at `p` is centred on
+    /// the old pixel one up and left of `p`.
+    pub fn enhance(&self, image: &Image) -> Image {
+        let pixels = Grid::from_fn(image.pixels.width() + 2, image.pixels.height() + 2, |p| {
+            let index = (-2..=0).flat_map(|dy| (-2..=0).map(move |dx| (dx, dy)))
+                .fold(0, |index, (dx, dy)| index << 1 | image.get(p, dx, dy) as usize);
+            self.data[index]
+        });
+        let new_edge = if image.edge {
+            self.data[511]
+        } else {
+            self.data[0]
+        };
+        Image {
+            pixels,
+            edge: new_edge
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't a player's starting position
+    InvalidPlayer(Span),
+    /// A starting position which isn't a whole number
+    InvalidPosition(Span)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidPlayer(span) => write!(f, "expected `Player <n> starting position: <position>`, found `{}`", span.text),
+            Error::InvalidPosition(span) => write!(f, "expected a starting position, found `{}`", span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidPlayer(span) | Error::InvalidPosition(span) => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidPlayer(span) | Error::InvalidPosition(span) => Some(span)
+        }
+    }
+}
\ No newline at end of file
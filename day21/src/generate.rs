@@ -0,0 +1,10 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 2;
+
+/// Starting positions for `size` players, though the game is only defined for two.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    (1..=size).map(|player| format!("Player {} starting position: {}", player, rng.between(1, 10)))
+        .collect::<Vec<_>>().join("\n")
+}
\ No newline at end of file
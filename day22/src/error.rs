@@ -0,0 +1,40 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't a state and a cuboid separated by a space
+    InvalidStep(Span),
+    /// A state other than on or off
+    InvalidState(Span),
+    /// A coordinate which isn't an axis assigned a range
+    InvalidRange(Span),
+    /// A range bound which isn't a whole number
+    InvalidNumber(Span)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidStep(span) => write!(f, "expected `on|off x=<min>..<max>,y=<min>..<max>,z=<min>..<max>`, found `{}`", span.text),
+            Error::InvalidState(span) => write!(f, "expected `on` or `off`, found `{}`", span.text),
+            Error::InvalidRange(span) => write!(f, "expected `<axis>=<min>..<max>`, found `{}`", span.text),
+            Error::InvalidNumber(span) => write!(f, "expected a number, found `{}`", span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidStep(span) | Error::InvalidState(span) | Error::InvalidRange(span) | Error::InvalidNumber(span) => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidStep(span) | Error::InvalidState(span) | Error::InvalidRange(span) | Error::InvalidNumber(span) => Some(span)
+        }
+    }
+}
\ No newline at end of file
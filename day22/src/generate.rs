@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 420;
+
+/// `size` reboot steps: the first twenty within the initialization region, around 50 cubes of the
+/// origin, and the rest large cuboids far beyond it. The first step of each kind turns cubes on.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    for i in 0..size {
+        let (limit, extent) = if i < 20 { (50, 30) } else { (100000, 30000) };
+        let on = i == 0 || i == 20 || rng.chance(0.6);
+        let ranges: Vec<String> = ["x", "y", "z"].iter().map(|axis| {
+            let min = rng.between(-limit, limit - 1);
+            let max = rng.between(min, (min + extent).min(limit));
+            format!("{}={}..{}", axis, min, max)
+        }).collect();
+        lines.push(format!("{} {}", if on { "on" } else { "off" }, ranges.join(",")));
+    }
+    lines.join("\n")
+}
\ No newline at end of file
@@ -0,0 +1,274 @@
+use std::str::FromStr;
+use std::collections::HashSet;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+pub struct RebootStep {
+    value: bool,
+    cubeoid: Cubeoid
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Point {
+    x: isize,
+    y: isize,
+    z: isize
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Cubeoid {
+    min: Point,
+    max: Point
+}
+
+pub struct Reactor {
+    cubeoids: HashSet<Cubeoid>
+}
+
+#[cfg(test)]
+mod tests;
+
+pub type Input = Vec<RebootStep>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing every state, range and number which isn't valid.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<RebootStep, Error>(text)
+}
+
+pub fn part1(steps: &Input) -> Answer {
+    reboot(steps).count_cubes(&Some(50)).into()
+}
+
+pub fn part2(steps: &Input) -> Answer {
+    reboot(steps).count_cubes(&None).into()
+}
+
+pub fn reboot(steps: &[RebootStep]) -> Reactor {
+    let mut reactor = Reactor::new();
+    for step in steps {
+        reactor.set(step.value, step.cubeoid.clone());
+    }
+    reactor
+}
+
+impl FromStr for Cubeoid {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Self::parse(line, line)
+    }
+}
+
+impl Cubeoid {
+    /// Parses part of a line, so that errors can point at where in the line they occurred.
+    fn parse(line: &str, part: &str) -> Result<Self, Error> {
+        let coordinates: Vec<&str> = part.split(",").collect();
+        if coordinates.len() != 3 {
+            return Err(Error::InvalidStep(Span::line(line)));
+        }
+        let mut min = Point::new();
+        let mut max = Point::new();
+        (min.x, max.x) = Self::parse_range(line, coordinates[0])?;
+        (min.y, max.y) = Self::parse_range(line, coordinates[1])?;
+        (min.z, max.z) = Self::parse_range(line, coordinates[2])?;
+        Ok(Self {
+            min,
+            max
+        })
+    }
+
+    fn parse_range(line: &str, assignment: &str) -> Result<(isize, isize), Error> {
+        let (min, max) = Self::split_range(line, assignment)?;
+        Ok((Self::parse_value(line, min)?, Self::parse_value(line, max)?))
+    }
+
+    /// Every problem with part of a line, where `parse` stops at the first.
+    fn problems(line: &str, part: &str) -> Vec<Error> {
+        let coordinates: Vec<&str> = part.split(",").collect();
+        if coordinates.len() != 3 {
+            return vec![Error::InvalidStep(Span::line(line))];
+        }
+        coordinates.into_iter().flat_map(|assignment| match Self::split_range(line, assignment) {
+            Ok((min, max)) => [min, max].into_iter().filter_map(|v| Self::parse_value(line, v).err()).collect(),
+            Err(e) => vec![e]
+        }).collect()
+    }
+
+    fn split_range<'a>(line: &str, assignment: &'a str) -> Result<(&'a str, &'a str), Error> {
+        let invalid = || Error::InvalidRange(Span::new(line, assignment));
+        let (_, range) = assignment.split_once("=").filter(|(_, r)| !r.contains("=")).ok_or_else(invalid)?;
+        range.split_once("..").filter(|(_, max)| !max.contains("..")).ok_or_else(invalid)
+    }
+
+    fn parse_value(line: &str, value: &str) -> Result<isize, Error> {
+        value.parse().map_err(|_| Error::InvalidNumber(Span::new(line, value)))
+    }
+
+    fn count_cubes(&self, limit: &Option<usize>) -> usize {
+        Self::limited_range(self.min.x, self.max.x, limit) * Self::limited_range(self.min.y, self.max.y, limit) * Self::limited_range(self.min.z, self.max.z, limit)
+    }
+
+    fn limited_range(mut min: isize, mut max: isize, limit: &Option<usize>) -> usize {
+        if let Some(l) = limit {
+            if min < -(*l as isize) {
+                min = -(*l as isize);
+                if max < -(*l as isize) {
+                    return 0;
+                }
+            }
+            if max > *l as isize {
+                max = *l as isize;
+                if min > *l as isize {
+                    return 0;
+                }
+            }
+        }
+        (max - min + 1).try_into().unwrap()
+    }
+
+    fn overlaps(&self, other: &Cubeoid) -> bool {
+        Self::overlap_1d(self.min.x, self.max.x, other.min.x, other.max.x)
+            && Self::overlap_1d(self.min.y, self.max.y, other.min.y, other.max.y)
+            && Self::overlap_1d(self.min.z, self.max.z, other.min.z, other.max.z)
+    }
+
+    fn overlap_1d(a_min: isize, a_max: isize, b_min: isize, b_max: isize) -> bool {
+        a_max >= b_min && b_max >= a_min
+    }
+
+    fn subtract(&self, other: &Cubeoid) -> Vec<Cubeoid> {
+        let mut new_cubeoids = Vec::new();
+        for (x_min, x_max) in Self::segments_1d(self.min.x, self.max.x, other.min.x, other.max.x) {
+            for (y_min, y_max) in Self::segments_1d(self.min.y, self.max.y, other.min.y, other.max.y) {
+                for (z_min, z_max) in Self::segments_1d(self.min.z, self.max.z, other.min.z, other.max.z) {
+                    let new_cubeoid = Cubeoid {
+                        min: Point {
+                            x: x_min,
+                            y: y_min,
+                            z: z_min
+                        },
+                        max: Point {
+                            x: x_max,
+                            y: y_max,
+                            z: z_max
+                        }
+                    };
+                    if !new_cubeoid.overlaps(other) {
+                        new_cubeoids.push(new_cubeoid);
+                    }
+                }
+            }
+        }
+        new_cubeoids
+    }
+
+    fn segments_1d(a_min: isize, a_max: isize, b_min: isize, b_max: isize) -> Vec<(isize, isize)> {
+        let mut edges = vec![a_min];
+        if b_min <= a_max && b_min >= a_min {
+            edges.push(b_min);
+        }
+        if b_max <= a_max && b_max >= a_min {
+            edges.push(b_max + 1);
+        }
+        edges.push(a_max + 1);
+        let mut segments = Vec::new();
+        for i in 1..edges.len() {
+            let left = edges[i-1];
+            let right = edges[i]-1;
+            if left <= right {
+                segments.push((left, right));
+            }
+        }
+        segments
+    }
+}
+
+impl FromStr for RebootStep {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (state, cubeoid) = Self::parts(line)?;
+        Ok(Self {
+            value: Self::parse_state(line, state)?,
+            cubeoid: Cubeoid::parse(line, cubeoid)?
+        })
+    }
+}
+
+impl Validate for RebootStep {
+    fn problems(line: &str) -> Vec<Error> {
+        match Self::parts(line) {
+            Ok((state, cubeoid)) => Self::parse_state(line, state).err().into_iter().chain(Cubeoid::problems(line, cubeoid)).collect(),
+            Err(e) => vec![e]
+        }
+    }
+}
+
+impl RebootStep {
+    fn parts(line: &str) -> Result<(&str, &str), Error> {
+        let parts: Vec<&str> = line.split(" ").collect();
+        match parts[..] {
+            [state, cubeoid] => Ok((state, cubeoid)),
+            _ => Err(Error::InvalidStep(Span::line(line)))
+        }
+    }
+
+    fn parse_state(line: &str, state: &str) -> Result<bool, Error> {
+        match state {
+            "on" => Ok(true),
+            "off" => Ok(false),
+            _ => Err(Error::InvalidState(Span::new(line, state)))
+        }
+    }
+}
+
+impl Point {
+    fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            z: 0
+        }
+    }
+}
+
+impl Default for Reactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reactor {
+    pub fn new() -> Self {
+        Self {
+            cubeoids: HashSet::new()
+        }
+    }
+
+    pub fn count_cubes(&self, limit: &Option<usize>) -> usize {
+        self.cubeoids.iter().map(|c| c.count_cubes(limit)).sum()
+    }
+
+    fn set(&mut self, value: bool, cubeoid: Cubeoid) {
+        let conflicts: Vec<Cubeoid> = self.cubeoids.extract_if(|c| c.overlaps(&cubeoid)).collect();
+        for conflict in conflicts {
+            let replacements = conflict.subtract(&cubeoid);
+            for replacement in replacements {
+                self.cubeoids.insert(replacement);
+            }
+        }
+        if value {
+            self.cubeoids.insert(cubeoid);
+        }
+    }
+}
\ No newline at end of file
@@ -52,4 +52,14 @@ fn segments_1d_overlap_left_border() {
 fn segments_1d_overlap_right_border() {
     let res = Cubeoid::segments_1d(1,6,6,6);
     assert_eq!(res, [(1,5),(6,6)]);
-}
\ No newline at end of file
+}
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("on x=1..2,y=1..2,z=1..2\nof x=a..2,y=1-2,z=1..b\non x=1..2");
+    assert_eq!(errors.len(), 5);
+    assert!(matches!(&errors[0], Error::InvalidState(span) if span.line == 2 && span.text == "of"));
+    assert!(matches!(&errors[1], Error::InvalidNumber(span) if span.text == "a"));
+    assert!(matches!(&errors[2], Error::InvalidRange(span) if span.text == "y=1-2"));
+    assert!(matches!(&errors[3], Error::InvalidNumber(span) if span.text == "b"));
+    assert!(matches!(&errors[4], Error::InvalidStep(span) if span.line == 3));
+}
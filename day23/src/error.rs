@@ -0,0 +1,47 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A burrow diagram with the wrong number of lines
+    InvalidLineCount { expected: usize, found: usize },
+    /// A line of a room which is too short to reach every room
+    ShortLine { span: Span, expected: usize },
+    /// A hallway position or room slot which is neither an amphipod nor empty
+    InvalidAmphipod(Span),
+    /// A burrow without exactly enough of an amphipod to fill its room
+    WrongAmphipodCount { amphipod: char, expected: usize, found: usize },
+    /// A burrow in which the amphipods can't be organised
+    NoSolution
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidLineCount { expected, found } => write!(f, "expected {} lines describing the burrow, found {}", expected, found),
+            Error::ShortLine { span, expected } => write!(f, "expected at least {} chars, found `{}`", expected, span.text),
+            Error::InvalidAmphipod(span) => write!(f, "expected `A`, `B`, `C`, `D` or `.`, found `{}`", span.text),
+            Error::WrongAmphipodCount { amphipod, expected, found } => write!(f, "expected {} of amphipod `{}`, found {}", expected, amphipod, found),
+            Error::NoSolution => write!(f, "expected the amphipods to be organised, but found no way to")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidAmphipod(span) => Some(span),
+            Error::ShortLine { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidAmphipod(span) => Some(span),
+            Error::ShortLine { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
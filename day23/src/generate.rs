@@ -0,0 +1,24 @@
+use aoc_common::Rng;
+use crate::{organise, ROOM_SLOTS};
+
+pub const DEFAULT_SIZE: usize = 1;
+
+/// A burrow of shuffled amphipods, reshuffled until they can be organised. There's only one size of
+/// burrow, so `size` is ignored.
+pub fn generate(seed: u64, _size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    loop {
+        let mut amphipods: Vec<char> = "ABCD".chars().flat_map(|a| [a; ROOM_SLOTS]).collect();
+        rng.shuffle(&mut amphipods);
+        let mut lines = vec!["#############".to_string(), "#...........#".to_string()];
+        for (slot, row) in amphipods.chunks(4).enumerate() {
+            let (left, right) = if slot == 0 { ("###", "###") } else { ("  #", "#") };
+            lines.push(format!("{}{}{}", left, row.iter().map(|a| a.to_string()).collect::<Vec<_>>().join("#"), right));
+        }
+        lines.push("  #########".to_string());
+        let text = lines.join("\n");
+        if text.parse().is_ok_and(|state| organise(&state).is_ok()) {
+            return text;
+        }
+    }
+}
\ No newline at end of file
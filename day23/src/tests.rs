@@ -0,0 +1,14 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn state_round_trip() {
+    assert_round_trip(5, |seed| generate::generate(seed, 1).parse::<State>().unwrap());
+}
+
+#[test]
+fn organising_states_round_trip() {
+    let burrow: State = generate::generate(0, 1).parse().unwrap();
+    let (states, _) = organise(&burrow).unwrap();
+    assert_round_trip(states.len() as u64, |i| states[i as usize].clone());
+}
\ No newline at end of file
@@ -90,6 +90,12 @@ pub struct FunctionalArithmeticLogicUnit {
     input_counter: usize
 }
 
+impl Default for FunctionalArithmeticLogicUnit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FunctionalArithmeticLogicUnit {
     pub fn new() -> Self {
         Self {
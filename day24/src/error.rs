@@ -0,0 +1,40 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't an instruction with the right number of arguments
+    InvalidInstruction(Span),
+    /// An operator other than add, mul, div, mod or eql
+    InvalidOperator(Span),
+    /// A variable other than w, x, y or z
+    InvalidVariable(Span),
+    /// An argument which is neither a variable nor a whole number
+    InvalidExpression(Span)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidInstruction(span) => write!(f, "expected `inp <variable>` or `<operator> <variable> <variable|number>`, found `{}`", span.text),
+            Error::InvalidOperator(span) => write!(f, "expected `add`, `mul`, `div`, `mod` or `eql`, found `{}`", span.text),
+            Error::InvalidVariable(span) => write!(f, "expected `w`, `x`, `y` or `z`, found `{}`", span.text),
+            Error::InvalidExpression(span) => write!(f, "expected a variable or number, found `{}`", span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidInstruction(span) | Error::InvalidOperator(span) | Error::InvalidVariable(span) | Error::InvalidExpression(span) => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidInstruction(span) | Error::InvalidOperator(span) | Error::InvalidVariable(span) | Error::InvalidExpression(span) => Some(span)
+        }
+    }
+}
\ No newline at end of file
@@ -19,13 +19,14 @@ impl fmt::Display for Function {
 }
 
 impl Function {
+    #[allow(dead_code)]
     pub fn short_string(&self, goal_length: usize) -> String {
         match self {
             Function::Literal(l) => format!("{}", l),
             Function::Input(i) => format!("I{{{}}}", i),
             Function::Operation(f1, op, f2) => {
                 if goal_length == 1 {
-                    format!("...")
+                    "...".to_string()
                 } else {
                     let g = goal_length / 2;
                     format!("({} {} {})", f1.short_string(g), op, f2.short_string(g))
@@ -163,11 +164,7 @@ impl Function {
     }
 
     pub fn is_input(&self) -> bool {
-        if let Function::Input(_i) = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Function::Input(_i))
     }
 
     pub fn _evaluate(&self, inputs: &Vec<usize>) -> isize {
@@ -219,8 +216,9 @@ impl DigitValue {
         value
     }
 
+    #[allow(dead_code)]
     fn impossible(&self) -> bool {
-        for (v, p) in self.possible.iter().enumerate() {
+        for p in self.possible.iter() {
             if *p {
                 return false;
             }
@@ -228,6 +226,7 @@ impl DigitValue {
         true
     }
 
+    #[allow(dead_code)]
     fn min(&self) -> Option<isize> {
         for (v, p) in self.possible.iter().enumerate() {
             if *p {
@@ -237,6 +236,7 @@ impl DigitValue {
         None
     }
 
+    #[allow(dead_code)]
     fn max(&self) -> Option<isize> {
         for (v, p) in self.possible.iter().enumerate().rev() {
             if *p {
@@ -290,10 +290,16 @@ impl Display for Solution {
     }
 }
 
+impl Default for Solution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Solution {
     pub fn new() -> Self {
         let mut vec = Vec::new();
-        for i in 0..14  {
+        for _ in 0..14  {
             vec.push(DigitValue::new());
         }
         Self {
@@ -301,6 +307,7 @@ impl Solution {
         }
     }
 
+    #[allow(dead_code)]
     pub fn impossible(&self) -> bool {
         for input in &self.inputs {
             if input.impossible() {
@@ -410,17 +417,17 @@ impl Solution {
                     //println!("{}No Soln", show_depth(depth));
                     vec![]
                 } else {
-                    println!("{}Valid {}", show_depth(depth), self);
+                    eprintln!("{}Valid {}", show_depth(depth), self);
                     vec![self]
                 }
             },
             Function::Input(input) =>  {
-                if must_equal < 1 || must_equal > 9 || !self.inputs[*input].possible[must_equal as usize] {
+                if !(1..=9).contains(&must_equal) || !self.inputs[*input].possible[must_equal as usize] {
                     //println!("{}No Soln", show_depth(depth));
                     vec![]
                 } else {
                     self.inputs[*input].must_be(must_equal);
-                    println!("{}Valid {}", show_depth(depth), self);
+                    eprintln!("{}Valid {}", show_depth(depth), self);
                     vec![self]
                 }
             },
@@ -428,7 +435,7 @@ impl Solution {
                 // if know both sides, just check it
                 if let (Some(known1), Some(known2)) = (self.known_value_of(f1), self.known_value_of(f2)) {
                     return if op.operate(known1, known2) == must_equal {
-                        println!("{}Valid {}", show_depth(depth), self);
+                        eprintln!("{}Valid {}", show_depth(depth), self);
                         vec![self]
                     } else {
                         //println!("{}No Soln", show_depth(depth));
@@ -495,12 +502,12 @@ impl Solution {
                         let mut set_one_value = self.clone();
                         set_one_value.inputs[i].must_be(p);
                         solutions.append(&mut set_one_value.find(function, must_equal, false, depth + 1)); // can I pass through return_first_solution?
-                        if return_first_solution && solutions.len() > 0 {
+                        if return_first_solution && !solutions.is_empty() {
                             break;
                         }
                     }
                     for s in &solutions {
-                        println!("{}Many valid {}", show_depth(depth), s);
+                        eprintln!("{}Many valid {}", show_depth(depth), s);
                     }
                     return solutions;
                 }
@@ -0,0 +1,30 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 14;
+
+/// A MONAD program checking a model number of `size` digits, in the same shape as the real one: a block
+/// per digit, each either pushing the digit plus an offset onto a base 26 stack held in z, or popping
+/// the stack and pushing again unless the digit matches. Pops are matched to earlier pushes, with offsets
+/// allowing a match, so that some model number is valid.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut stack = Vec::new();
+    let mut blocks = Vec::new();
+    for i in 0..size {
+        let remaining = size - i;
+        let push = stack.is_empty() || (stack.len() < remaining && rng.chance(0.5));
+        if push {
+            let offset = rng.between(0, 16);
+            stack.push(offset);
+            blocks.push(block(1, rng.between(10, 16), offset));
+        } else {
+            let pushed = stack.pop().unwrap();
+            blocks.push(block(26, rng.between(-8, 8) - pushed, rng.between(0, 16)));
+        }
+    }
+    blocks.join("\n")
+}
+
+fn block(divide: i64, check: i64, offset: i64) -> String {
+    format!("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y", divide, check, offset)
+}
\ No newline at end of file
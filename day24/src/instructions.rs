@@ -1,6 +1,9 @@
 
 use std::str::FromStr;
 use std::fmt;
+use aoc_common::Span;
+use aoc_common::input::Validate;
+use crate::Error;
 
 #[derive(Clone, PartialEq)]
 pub enum Operator {
@@ -32,7 +35,7 @@ pub enum Instruction {
 }
 
 impl FromStr for Operator {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(word: &str) -> Result<Self, Self::Err> {
         match word {
@@ -41,7 +44,7 @@ impl FromStr for Operator {
             "div" => Ok(Self::Divide),
             "mod" => Ok(Self::Modulo),
             "eql" => Ok(Self::Equal),
-            _ => Err(format!("Invalid operator: {}", word))
+            _ => Err(Error::InvalidOperator(Span::line(word)))
         }
     }
 }
@@ -59,7 +62,7 @@ impl fmt::Display for Operator {
 }
 
 impl FromStr for Variable {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(letter: &str) -> Result<Self, Self::Err> {
         match letter {
@@ -67,39 +70,65 @@ impl FromStr for Variable {
             "x" => Ok(Self::X),
             "y" => Ok(Self::Y),
             "z" => Ok(Self::Z),
-            _ => Err(format!("Invalid variable: {}", letter))
+            _ => Err(Error::InvalidVariable(Span::line(letter)))
         }
     }
 }
 
 impl FromStr for Expression {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(expression: &str) -> Result<Self, Self::Err> {
         Ok(match expression.parse::<Variable>() {
             Ok(var) => Expression::Variable(var),
-            Err(_) => Expression::Literal(expression.parse::<isize>().map_err(|e| e.to_string())?)
+            Err(_) => Expression::Literal(expression.parse::<isize>().map_err(|_| Error::InvalidExpression(Span::line(expression)))?)
         })
     }
 }
 
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         let words: Vec<&str> = line.split(" ").collect();
-        match (words[0], words.len() - 1) {
-            ("inp", 1) => Ok(Self::Input(words[1].parse::<Variable>().map_err(|e| e.to_string())?)),
-            (op, 2) => Ok(Self::Operation(
-                words[1].parse::<Variable>().map_err(|e| e.to_string())?,
-                op.parse()?,
-                words[2].parse::<Expression>().map_err(|e| e.to_string())?,
+        match words[..] {
+            ["inp", variable] => Ok(Self::Input(Self::variable(line, variable)?)),
+            [op, variable, expression] => Ok(Self::Operation(
+                Self::variable(line, variable)?,
+                Self::operator(line, op)?,
+                Self::expression(line, expression)?,
             )),
-            _ => Err(format!("Invalid instruction: {}", line))
+            _ => Err(Error::InvalidInstruction(Span::line(line)))
         }
     }
 }
 
+impl Validate for Instruction {
+    fn problems(line: &str) -> Vec<Error> {
+        let words: Vec<&str> = line.split(" ").collect();
+        match words[..] {
+            ["inp", variable] => Self::variable(line, variable).err().into_iter().collect(),
+            [op, variable, expression] => [Self::operator(line, op).err(), Self::variable(line, variable).err(), Self::expression(line, expression).err()]
+                .into_iter().flatten().collect(),
+            _ => vec![Error::InvalidInstruction(Span::line(line))]
+        }
+    }
+}
+
+impl Instruction {
+    fn variable(line: &str, word: &str) -> Result<Variable, Error> {
+        word.parse().map_err(|_| Error::InvalidVariable(Span::new(line, word)))
+    }
+
+    fn operator(line: &str, word: &str) -> Result<Operator, Error> {
+        word.parse().map_err(|_| Error::InvalidOperator(Span::new(line, word)))
+    }
+
+    fn expression(line: &str, word: &str) -> Result<Expression, Error> {
+        word.parse().map_err(|_| Error::InvalidExpression(Span::new(line, word)))
+    }
+}
+
 impl Operator {
     pub fn operate(&self, a: isize, b: isize) -> isize {
         match self {
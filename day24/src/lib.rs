@@ -0,0 +1,27 @@
+use aoc_common::input;
+
+mod instructions;
+mod alu;
+mod functions;
+pub mod generate;
+mod error;
+
+pub use crate::error::Error;
+pub use crate::functions::Solution;
+pub use crate::instructions::{Instruction, Variable};
+pub use crate::alu::{ArithmeticLogicUnit, ReverseArithmeticLogicUnit, FunctionalArithmeticLogicUnit};
+
+#[cfg(test)]
+mod tests;
+
+pub type Input = Vec<Instruction>;
+
+/// Neither part is solved yet, so this only parses the program for exploring in `main`.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing every operator, variable and expression which isn't valid.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Instruction, Error>(text)
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("inp w\nmul q 2\nsub x y\nfoo v w\ninp");
+    assert_eq!(errors.len(), 5);
+    assert!(matches!(&errors[0], Error::InvalidVariable(span) if span.line == 2 && span.text == "q"));
+    assert!(matches!(&errors[1], Error::InvalidOperator(span) if span.line == 3 && span.text == "sub"));
+    assert!(matches!(&errors[2], Error::InvalidOperator(span) if span.line == 4 && span.text == "foo"));
+    assert!(matches!(&errors[3], Error::InvalidVariable(span) if span.line == 4 && span.text == "v"));
+    assert!(matches!(&errors[4], Error::InvalidInstruction(span) if span.line == 5));
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, GridError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A location which is neither a sea cucumber nor empty
+    InvalidCell(Span),
+    /// A row with a different number of locations to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// Input without any rows
+    EmptyMap
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidCell(span) => write!(f, "expected `>`, `v` or `.`, found `{}`", span.text),
+            Error::InvalidRowLength { span, expected } => write!(f, "expected {} locations in row, found `{}`", expected, span.text),
+            Error::EmptyMap => write!(f, "expected at least one row of locations")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidCell(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            Error::EmptyMap => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidCell(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            Error::EmptyMap => None
+        }
+    }
+}
+
+impl From<GridError> for Error {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::InvalidCell(span) => Error::InvalidCell(span),
+            GridError::InvalidRowLength { span, expected } => Error::InvalidRowLength { span, expected },
+            GridError::Empty => Error::EmptyMap
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use aoc_common::{Grid, Rng};
+use crate::{Direction, Simulation};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Steps to wait for the sea cucumbers to stop before giving up on a map.
+const MAX_STEPS: usize = 10000;
+
+/// A `size` by `size` map with about a quarter of the locations holding each herd, redrawn until the
+/// sea cucumbers stop moving within a reasonable number of steps. A sparse map can keep some moving forever.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+    loop {
+        let cucumbers = Grid::from_fn(size, size, |_| match rng.below(4) {
+            0 => Some(Direction::Right),
+            1 => Some(Direction::Down),
+            _ => None
+        }).wrapping(true);
+        let mut simulation = Simulation { cucumbers };
+        let text = simulation.to_string();
+        if (0..MAX_STEPS).any(|_| !simulation.step()) {
+            return text;
+        }
+    }
+}
\ No newline at end of file
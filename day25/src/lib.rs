@@ -0,0 +1,108 @@
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Answer, Grid};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Direction {
+    Right,
+    Down
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Simulation {
+    cucumbers: Grid<Option<Direction>>
+}
+
+pub type Input = Simulation;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    text.parse()
+}
+
+/// Checks the map, listing every location which is neither a sea cucumber nor empty and every row of
+/// the wrong length.
+pub fn validate(text: &str) -> Vec<Error> {
+    Grid::validate(text, parse_location).into_iter().map(Error::from).collect()
+}
+
+pub fn part1(simulation: &Input) -> Answer {
+    simulation.clone().run_until_stopped().into()
+}
+
+impl FromStr for Simulation {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let cucumbers = Grid::parse(text, parse_location)?;
+        Ok(Self {
+            cucumbers: cucumbers.wrapping(true)
+        })
+    }
+}
+
+/// A sea cucumber facing right or down, or an empty location.
+fn parse_location(c: char) -> Option<Option<Direction>> {
+    match c {
+        '>' => Some(Some(Direction::Right)),
+        'v' => Some(Some(Direction::Down)),
+        '.' => Some(None),
+        _ => None
+    }
+}
+
+impl Display for Simulation {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.cucumbers.map(|c| match c {
+            Some(Direction::Right) => '>',
+            Some(Direction::Down) => 'v',
+            None => '.'
+        }))
+    }
+}
+
+impl Simulation {
+    /// Steps until no sea cucumber moves, returning the number of the first step on which none did.
+    pub fn run_until_stopped(&mut self) -> usize {
+        let mut step = 1;
+        while self.step() {
+            step += 1;
+        }
+        step
+    }
+
+    fn step(&mut self) -> bool {
+        let right = self.half_step(Direction::Right);
+        let down = self.half_step(Direction::Down);
+        right || down
+    }
+
+    fn half_step(&mut self, direction: Direction) -> bool {
+        let (dx, dy) = match direction {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1)
+        };
+        let mut new_cucumbers = self.cucumbers.clone();
+        let mut changes = false;
+        for (p, d) in self.cucumbers.iter() {
+            if *d == Some(direction) {
+                let adjacent = self.cucumbers.offset(p, dx, dy).expect("sea cucumbers wrap around the edges");
+                if self.cucumbers[adjacent].is_none() {
+                    new_cucumbers[adjacent] = Some(direction);
+                    new_cucumbers[p] = None;
+                    changes = true;
+                }
+            }
+        }
+        self.cucumbers = new_cucumbers;
+        changes
+    }
+}
\ No newline at end of file
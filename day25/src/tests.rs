@@ -0,0 +1,16 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn simulation_round_trip() {
+    assert_round_trip(5, |seed| generate::generate(seed, 10).parse::<Simulation>().unwrap());
+}
+
+#[test]
+fn moving_simulation_round_trip() {
+    let mut simulation: Simulation = generate::generate(0, 10).parse().unwrap();
+    assert_round_trip(10, |_| {
+        simulation.step();
+        simulation.clone()
+    });
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+use crate::Criteria;
+
+/// At most this many candidates are listed when a rating can't be settled.
+const MAX_CANDIDATES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A character other than 0 or 1
+    InvalidBit(Span),
+    /// A number with a different number of bits to the first
+    InvalidLength { span: Span, expected: usize },
+    /// A number with more bits than fit in a `u64`
+    TooWide(Span),
+    /// Input without any numbers
+    NoNumbers,
+    /// Filtering for a rating left more than one number, which the criteria can't tell apart
+    NoSingleRating { criteria: Criteria, width: usize, candidates: Vec<u64> }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidBit(span) => write!(f, "expected `0` or `1`, found `{}`", span.text),
+            Error::InvalidLength { span, expected } => write!(f, "expected {} bits, found `{}`", expected, span.text),
+            Error::TooWide(span) => write!(f, "expected at most 64 bits, found {}", span.text.chars().count()),
+            Error::NoNumbers => write!(f, "expected at least one number"),
+            Error::NoSingleRating { criteria, width, candidates } => {
+                let listed: Vec<String> = candidates.iter().take(MAX_CANDIDATES).map(|c| format!("{:0width$b}", c, width = width)).collect();
+                write!(f, "filtering by {} left {} numbers: {}", criteria, candidates.len(), listed.join(", "))?;
+                if candidates.len() > MAX_CANDIDATES {
+                    write!(f, " and {} more", candidates.len() - MAX_CANDIDATES)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidBit(span) | Error::TooWide(span) => Some(span),
+            Error::InvalidLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidBit(span) | Error::TooWide(span) => Some(span),
+            Error::InvalidLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` distinct binary numbers of 12 bits, or more if needed to keep them distinct. Duplicates would
+/// leave the life support ratings without a single number to settle on.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut bits = 12;
+    while size > 1 << bits {
+        bits += 1;
+    }
+    let mut numbers = HashSet::new();
+    let mut lines = Vec::new();
+    while lines.len() < size {
+        let number = rng.below(1 << bits);
+        if numbers.insert(number) {
+            lines.push(format!("{:0width$b}", number, width = bits));
+        }
+    }
+    lines.join("\n")
+}
\ No newline at end of file
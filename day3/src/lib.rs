@@ -0,0 +1,81 @@
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+
+pub mod generate;
+mod error;
+mod report;
+
+pub use error::Error;
+pub use report::{Common, Criteria, DiagnosticReport, Selection, Tie, count, selected};
+
+#[cfg(test)]
+mod tests;
+
+pub type Input = DiagnosticReport;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let mut width = None;
+    let mut rows = Vec::new();
+    for (i, line) in input::lines(text).enumerate() {
+        let mut number = 0;
+        for (c, bit) in line.chars().enumerate() {
+            match bit {
+                '0' | '1' if c < DiagnosticReport::MAX_WIDTH => number = number << 1 | (bit == '1') as u64,
+                '0' | '1' => return Err(Error::TooWide(Span::line(line).on_line(i + 1))),
+                _ => return Err(Error::InvalidBit(Span::char_at(line, c).on_line(i + 1)))
+            }
+        }
+        let length = line.chars().count();
+        match width {
+            Some(expected) if length != expected => return Err(Error::InvalidLength { span: Span::line(line).on_line(i + 1), expected }),
+            Some(_) => (),
+            None => width = Some(length)
+        }
+        rows.push(number);
+    }
+    match width {
+        Some(width) => Ok(DiagnosticReport::new(width, rows)),
+        None => Err(Error::NoNumbers)
+    }
+}
+
+/// Checks every line of the input, listing each character which isn't a bit and each number with a
+/// different number of bits to the first.
+pub fn validate(text: &str) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let mut expected = None;
+    for (i, line) in input::lines(text).enumerate() {
+        for (c, bit) in line.chars().enumerate() {
+            if bit != '0' && bit != '1' {
+                errors.push(Error::InvalidBit(Span::char_at(line, c).on_line(i + 1)));
+            }
+        }
+        let length = line.chars().count();
+        if length > DiagnosticReport::MAX_WIDTH && expected.is_none() {
+            errors.push(Error::TooWide(Span::line(line).on_line(i + 1)));
+        }
+        match expected {
+            Some(expected) if length != expected => errors.push(Error::InvalidLength { span: Span::line(line).on_line(i + 1), expected }),
+            Some(_) => (),
+            None => expected = Some(length)
+        }
+    }
+    if expected.is_none() {
+        errors.push(Error::NoNumbers);
+    }
+    errors
+}
+
+pub fn part1(report: &Input) -> Answer {
+    let (gamma, epsilon) = report.power_rates();
+    (gamma as u128 * epsilon as u128).into()
+}
+
+pub fn part2(report: &Input) -> Result<Answer, Error> {
+    life_support(report, Criteria::OXYGEN, Criteria::CO2).map(Answer::from)
+}
+
+/// The life support rating: the oxygen generator rating multiplied by the CO2 scrubber rating.
+pub fn life_support(report: &Input, oxygen: Criteria, co2: Criteria) -> Result<u128, Error> {
+    Ok(report.rating(oxygen)? as u128 * report.rating(co2)? as u128)
+}
\ No newline at end of file
@@ -0,0 +1,190 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::Error;
+
+/// The diagnostic report: binary numbers of up to 64 bits, all the same width. As well as each number,
+/// every bit position is kept as a column packed 64 rows to a word, so that counting the ones in a
+/// column among some set of rows is a popcount over a few words, whatever the width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    width: usize,
+    rows: Vec<u64>,
+    columns: Vec<Vec<u64>>
+}
+
+/// A set of rows of a report, one bit per row, which filtering narrows down in place.
+pub type Selection = Vec<u64>;
+
+/// Whether a rating keeps the rows with the most or the least common bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Common {
+    Most,
+    Least
+}
+
+/// What a rating keeps at a bit where as many rows have a 0 as a 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    One,
+    Zero,
+    /// Every row, leaving the tie to be settled by the bits after
+    Both
+}
+
+/// How to filter the rows for a rating, written `most:1`, `least:0`, `most:both` and so on. Whatever
+/// the criteria, a bit which every row shares is kept, so filtering never leaves no rows at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Criteria {
+    pub common: Common,
+    pub tie: Tie
+}
+
+impl Criteria {
+    /// The oxygen generator rating keeps the most common bit, or 1 on a tie.
+    pub const OXYGEN: Criteria = Criteria { common: Common::Most, tie: Tie::One };
+    /// The CO2 scrubber rating keeps the least common bit, or 0 on a tie.
+    pub const CO2: Criteria = Criteria { common: Common::Least, tie: Tie::Zero };
+}
+
+impl DiagnosticReport {
+    pub const MAX_WIDTH: usize = 64;
+
+    /// A report of numbers `width` bits wide. Bits are numbered from 0 for the most significant.
+    pub fn new(width: usize, rows: Vec<u64>) -> Self {
+        assert!(width <= Self::MAX_WIDTH, "numbers can't be more than {} bits wide", Self::MAX_WIDTH);
+        let mut columns = vec![vec![0; rows.len().div_ceil(64)]; width];
+        for (r, &row) in rows.iter().enumerate() {
+            for (bit, column) in columns.iter_mut().enumerate() {
+                column[r / 64] |= (row >> (width - 1 - bit) & 1) << (r % 64);
+            }
+        }
+        Self { width, rows, columns }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn rows(&self) -> &[u64] {
+        &self.rows
+    }
+
+    /// Every row of the report.
+    pub fn all(&self) -> Selection {
+        let mut selection = vec![u64::MAX; self.rows.len().div_ceil(64)];
+        if let Some(last) = selection.last_mut().filter(|_| !self.rows.len().is_multiple_of(64)) {
+            *last = (1 << (self.rows.len() % 64)) - 1;
+        }
+        selection
+    }
+
+    /// How many of the selected rows have a 1 at a bit position.
+    pub fn ones(&self, bit: usize, selection: &[u64]) -> usize {
+        self.columns[bit].iter().zip(selection).map(|(c, s)| (c & s).count_ones() as usize).sum()
+    }
+
+    /// The value of a bit to keep among the selected rows, or `None` to keep them all on a tie.
+    pub fn common_bit(&self, bit: usize, selection: &[u64], criteria: Criteria) -> Option<bool> {
+        let ones = self.ones(bit, selection);
+        let zeros = count(selection) - ones;
+        if ones == 0 || zeros == 0 {
+            return Some(ones > 0);
+        }
+        match (ones.cmp(&zeros), criteria.tie) {
+            (Ordering::Equal, Tie::One) => Some(true),
+            (Ordering::Equal, Tie::Zero) => Some(false),
+            (Ordering::Equal, Tie::Both) => None,
+            (order, _) => Some((order == Ordering::Greater) == (criteria.common == Common::Most))
+        }
+    }
+
+    /// Narrows a selection down to the rows with `value` at a bit position.
+    pub fn filter(&self, bit: usize, value: bool, selection: &mut [u64]) {
+        for (s, &c) in selection.iter_mut().zip(&self.columns[bit]) {
+            *s &= if value { c } else { !c };
+        }
+    }
+
+    /// The gamma rate, made of the most common bit in each position, and the epsilon rate, the least.
+    pub fn power_rates(&self) -> (u64, u64) {
+        let all = self.all();
+        let gamma = (0..self.width).fold(0, |rate, bit| rate << 1 | self.common_bit(bit, &all, Criteria::OXYGEN).unwrap() as u64);
+        let mask = if self.width == 64 { u64::MAX } else { (1 << self.width) - 1 };
+        (gamma, !gamma & mask)
+    }
+
+    /// A life support rating, filtering the rows one bit at a time until a single row is left. Rows which
+    /// the criteria can't tell apart, such as duplicates, are an error listing every one left.
+    pub fn rating(&self, criteria: Criteria) -> Result<u64, Error> {
+        let mut selection = self.all();
+        for bit in 0..self.width {
+            if count(&selection) <= 1 {
+                break;
+            }
+            if let Some(value) = self.common_bit(bit, &selection, criteria) {
+                self.filter(bit, value, &mut selection);
+            }
+        }
+        let rows: Vec<u64> = selected(&selection).map(|r| self.rows[r]).collect();
+        match rows[..] {
+            [row] => Ok(row),
+            _ => Err(Error::NoSingleRating { criteria, width: self.width, candidates: rows })
+        }
+    }
+}
+
+/// How many rows are selected.
+pub fn count(selection: &[u64]) -> usize {
+    selection.iter().map(|s| s.count_ones() as usize).sum()
+}
+
+/// The index of each selected row.
+pub fn selected(selection: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    selection.iter().enumerate().flat_map(|(w, &word)| (0..64).filter(move |b| word >> b & 1 == 1).map(move |b| w * 64 + b))
+}
+
+impl Display for Criteria {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        let common = match self.common {
+            Common::Most => "most",
+            Common::Least => "least"
+        };
+        let tie = match self.tie {
+            Tie::One => "1",
+            Tie::Zero => "0",
+            Tie::Both => "both"
+        };
+        write!(f, "{}:{}", common, tie)
+    }
+}
+
+impl FromStr for Criteria {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid criteria: {}, expected `most` or `least`, then `:1`, `:0` or `:both`", text);
+        let (common, tie) = text.split_once(":").ok_or_else(invalid)?;
+        let common = match common {
+            "most" => Common::Most,
+            "least" => Common::Least,
+            _ => return Err(invalid())
+        };
+        let tie = match tie {
+            "1" => Tie::One,
+            "0" => Tie::Zero,
+            "both" => Tie::Both,
+            _ => return Err(invalid())
+        };
+        Ok(Criteria { common, tie })
+    }
+}
\ No newline at end of file
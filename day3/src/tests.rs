@@ -0,0 +1,48 @@
+use super::*;
+
+/// Filters the slow way, copying the rows left at each step.
+fn naive_rating(rows: &[u64], width: usize, most: bool) -> u64 {
+    let mut rows = rows.to_vec();
+    for bit in (0..width).rev() {
+        if rows.len() == 1 {
+            break;
+        }
+        let ones = rows.iter().filter(|&&r| r >> bit & 1 == 1).count();
+        let zeros = rows.len() - ones;
+        let value = if ones == 0 || zeros == 0 { ones > 0 } else { (ones >= zeros) == most };
+        rows.retain(|&r| (r >> bit & 1 == 1) == value);
+    }
+    rows[0]
+}
+
+#[test]
+fn ratings_span_several_words() {
+    let report = parse(&generate::generate(3, 1000)).unwrap();
+    assert_eq!(report.len(), 1000);
+    assert_eq!(report.rating(Criteria::OXYGEN), Ok(naive_rating(report.rows(), report.width(), true)));
+    assert_eq!(report.rating(Criteria::CO2), Ok(naive_rating(report.rows(), report.width(), false)));
+}
+
+#[test]
+fn numbers_up_to_64_bits() {
+    let wide = format!("{}\n{}\n1{}", "1".repeat(64), "0".repeat(64), "0".repeat(63));
+    let report = parse(&wide).unwrap();
+    assert_eq!(report.power_rates(), (1 << 63, u64::MAX >> 1));
+    assert_eq!(report.rating(Criteria::OXYGEN), Ok(u64::MAX));
+    assert_eq!(report.rating(Criteria::CO2), Ok(0));
+    assert!(matches!(parse(&"1".repeat(65)), Err(Error::TooWide(_))));
+}
+
+#[test]
+fn tie_policies() {
+    let report = parse("101\n100\n011\n010").unwrap();
+    assert_eq!(report.rating(Criteria::OXYGEN), Ok(0b101));
+    assert_eq!(report.rating("most:0".parse().unwrap()), Ok(0b010));
+    let both: Criteria = "least:both".parse().unwrap();
+    assert_eq!(both.to_string(), "least:both");
+    assert_eq!(report.rating(both), Err(Error::NoSingleRating { criteria: both, width: 3, candidates: vec![0b101, 0b100, 0b011, 0b010] }));
+    let duplicates = parse("110\n110\n001").unwrap();
+    let error = duplicates.rating(Criteria::OXYGEN).unwrap_err();
+    assert_eq!(error.to_string(), "filtering by most:1 left 2 numbers: 110, 110");
+    assert!("most".parse::<Criteria>().is_err());
+}
\ No newline at end of file
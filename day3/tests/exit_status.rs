@@ -0,0 +1,28 @@
+use std::process::Command;
+
+fn day3(args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_day3"));
+    command.current_dir(env!("CARGO_MANIFEST_DIR")).args(args);
+    command
+}
+
+#[test]
+fn failed_part_exits_with_error() {
+    let output = day3(&["test.txt", "--oxygen=most:both"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Part 1: 198"));
+    assert!(stdout.contains("Part 2: FAILED: filtering by most:both left 2 numbers"));
+}
+
+#[test]
+fn solved_parts_exit_successfully() {
+    assert_eq!(day3(&["test.txt"]).output().unwrap().status.code(), Some(0));
+}
+
+#[test]
+fn unknown_option_is_a_usage_error() {
+    let output = day3(&["test.txt", "--oxygn=most:0"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stdout).unwrap().starts_with("Unknown option: --oxygn\n"));
+}
@@ -0,0 +1,36 @@
+use crate::{Bingo, Board, Game, Win, WinRule};
+
+/// How a board not in the game would do if it were added: when it wins and how many of the game's
+/// boards win on an earlier call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub win: Win,
+    pub beaten_by: usize
+}
+
+/// The call on which each board wins, indexed by board, from the game's `wins` given by `timeline`.
+/// Boards don't affect each other, so this is the earliest each could win, whether or not the game
+/// would carry on that long.
+pub fn earliest_wins<'a>(game: &Game, wins: &'a [Win]) -> Vec<Option<&'a Win>> {
+    let mut earliest = vec![None; game.boards.len()];
+    for win in wins {
+        earliest[win.board] = Some(win);
+    }
+    earliest
+}
+
+/// Every board which doesn't win whatever happens, since the calls never complete it.
+pub fn never_winning(game: &Game, wins: &[Win]) -> Vec<usize> {
+    earliest_wins(game, wins).iter().enumerate().filter(|(_, w)| w.is_none()).map(|(b, _)| b).collect()
+}
+
+/// Plays a board which isn't in the game against its calls, numbering it as if it came after the
+/// game's boards, and compares it with the game's `wins` from `timeline` under the same rule. Gives
+/// `None` if it never wins.
+pub fn try_board(game: &Game, wins: &[Win], board: &Board, rule: WinRule) -> Option<Candidate> {
+    let mut bingo = Bingo::new(vec![board.clone()], rule);
+    let mut win = game.calls.iter().find_map(|&call| bingo.call(call).pop())?;
+    win.board = game.boards.len();
+    let beaten_by = wins.iter().take_while(|w| w.turn < win.turn).count();
+    Some(Candidate { win, beaten_by })
+}
\ No newline at end of file
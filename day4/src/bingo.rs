@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use crate::{Board, Game, WinRule};
+
+/// A board winning: which board, on which turn and call, the numbers it won with and its score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub turn: usize,
+    pub call: usize,
+    pub numbers: Vec<usize>,
+    pub unmarked: usize
+}
+
+/// Plays calls against a set of boards. Every number is indexed to the cells it's in, so a call only
+/// touches the boards which have it, however many boards there are.
+pub struct Bingo {
+    boards: Vec<Board>,
+    cells: HashMap<usize, Vec<(usize, usize, usize)>>,
+    won: Vec<bool>,
+    rule: WinRule,
+    turn: usize
+}
+
+/// Every board to win in the order they win, with boards winning on the same call in the order given.
+/// Boards which never win are left out.
+pub fn timeline(game: &Game, rule: WinRule) -> Vec<Win> {
+    let mut bingo = Bingo::new(game.boards.clone(), rule);
+    game.calls.iter().flat_map(|&call| bingo.call(call)).collect()
+}
+
+impl Bingo {
+    pub fn new(boards: Vec<Board>, rule: WinRule) -> Self {
+        let mut cells: HashMap<usize, Vec<(usize, usize, usize)>> = HashMap::new();
+        for (b, board) in boards.iter().enumerate() {
+            for (r, row) in board.numbers.iter().enumerate() {
+                for (c, number) in row.iter().enumerate() {
+                    cells.entry(number.value).or_default().push((b, r, c));
+                }
+            }
+        }
+        Bingo {
+            won: vec![false; boards.len()],
+            boards,
+            cells,
+            rule,
+            turn: 0
+        }
+    }
+
+    /// Marks a call on every board, returning the boards which win with it. Boards which have already won
+    /// are still marked, but don't win again.
+    pub fn call(&mut self, call: usize) -> Vec<Win> {
+        let turn = self.turn;
+        self.turn += 1;
+        // Cells are indexed in board order, so each board's cells are together
+        let mut touched: Vec<usize> = Vec::new();
+        for &(b, r, c) in self.cells.get(&call).into_iter().flatten() {
+            self.boards[b].mark_at(r, c);
+            if touched.last() != Some(&b) {
+                touched.push(b);
+            }
+        }
+        let mut wins = Vec::new();
+        for b in touched {
+            if self.won[b] {
+                continue;
+            }
+            if let Some(numbers) = self.boards[b].complete(self.rule) {
+                self.won[b] = true;
+                wins.push(Win { board: b, turn, call, numbers, unmarked: self.boards[b].unmarked() });
+            }
+        }
+        wins
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    pub fn won(&self, board: usize) -> bool {
+        self.won[board]
+    }
+}
+
+impl Win {
+    /// The winning call multiplied by the sum of the board's unmarked numbers.
+    pub fn score(&self) -> usize {
+        self.call * self.unmarked
+    }
+}
\ No newline at end of file
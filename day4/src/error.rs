@@ -0,0 +1,53 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A call or board number which isn't a whole number
+    InvalidNumber(Span),
+    /// A board row with a different number of numbers to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// A board built from rows of different lengths
+    UnevenBoard { row: usize, expected: usize, found: usize },
+    /// A board without any numbers
+    EmptyBoard,
+    /// Input without a line of calls
+    MissingCalls,
+    /// Calls without any boards to play them on
+    NoBoards,
+    /// A game in which no board ever wins
+    NoWinner
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidNumber(span) => write!(f, "expected a number, found `{}`", span.text),
+            Error::InvalidRowLength { span, expected } => write!(f, "expected {} numbers in row, found `{}`", expected, span.text),
+            Error::UnevenBoard { row, expected, found } => write!(f, "expected {} numbers in every row, found {} in row {}", expected, found, row + 1),
+            Error::EmptyBoard => write!(f, "expected a board with at least one number"),
+            Error::MissingCalls => write!(f, "expected a line of calls followed by boards"),
+            Error::NoBoards => write!(f, "expected at least one board after the calls"),
+            Error::NoWinner => write!(f, "expected a board to win, but none did")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidNumber(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidNumber(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
\ No newline at end of file
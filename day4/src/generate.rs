@@ -0,0 +1,21 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// `size` 5x5 boards of distinct numbers below 100, and every number below 100 called once in a random
+/// order, so that every board eventually wins.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut calls: Vec<usize> = (0..100).collect();
+    rng.shuffle(&mut calls);
+    let mut sections = vec![calls.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",")];
+    for _ in 0..size {
+        let mut numbers: Vec<usize> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        let rows: Vec<String> = numbers[..25].chunks(5)
+            .map(|row| row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>().join(" "))
+            .collect();
+        sections.push(rows.join("\n"));
+    }
+    sections.join("\n\n")
+}
\ No newline at end of file
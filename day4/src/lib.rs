@@ -0,0 +1,285 @@
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Answer, Diagnostic, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod analysis;
+mod bingo;
+mod error;
+mod rule;
+
+pub use analysis::{Candidate, earliest_wins, never_winning, try_board};
+pub use bingo::{Bingo, Win, timeline};
+pub use error::Error;
+pub use rule::WinRule;
+
+#[cfg(test)]
+mod tests;
+
+pub struct Game {
+    pub calls: Vec<usize>,
+    pub boards: Vec<Board>
+}
+
+/// A board's numbers, along with how many are marked in each row, column and diagonal, and the sum of
+/// those which aren't, which marking keeps up to date. Boards needn't be square.
+#[derive(PartialEq, Clone)]
+pub struct Board {
+    numbers: Vec<Vec<Number>>,
+    row_hits: Vec<usize>,
+    column_hits: Vec<usize>,
+    diagonal_hits: [usize; 2],
+    marked: usize,
+    unmarked: usize
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Number {
+    value: usize,
+    marked: bool
+}
+
+pub type Input = Game;
+
+/// Parses the calls and boards of a game. Whether any board wins depends on the rule, so that's left to
+/// playing it.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let mut sections = input::numbered_sections(text).into_iter();
+    let (offset, line) = sections.next().ok_or(Error::MissingCalls)?;
+    let calls: Vec<usize> = line.split(",").map(|n| n.parse()
+        .map_err(|_| Error::InvalidNumber(Span::new(line, n).on_line(offset + 1)))).collect::<Result<_, _>>()?;
+    let boards: Vec<Board> = sections.map(input::parse_section).collect::<Result<_, _>>()?;
+    if boards.is_empty() {
+        return Err(Error::NoBoards);
+    }
+    Ok(Game { calls, boards })
+}
+
+/// Parses boards on their own, without calls, such as candidates to try against a game.
+pub fn parse_boards(text: &str) -> Result<Vec<Board>, Error> {
+    let boards: Vec<Board> = input::numbered_sections(text).into_iter().map(input::parse_section).collect::<Result<_, _>>()?;
+    if boards.is_empty() {
+        return Err(Error::NoBoards);
+    }
+    Ok(boards)
+}
+
+/// Checks the calls and boards without playing the game, listing every call which isn't a number, and
+/// every number and row of each board which isn't valid.
+pub fn validate(text: &str) -> Vec<Error> {
+    let mut sections = input::numbered_sections(text).into_iter();
+    let (offset, line) = match sections.next() {
+        Some(section) => section,
+        None => return vec![Error::MissingCalls]
+    };
+    let mut errors: Vec<Error> = line.split(",").filter(|n| n.parse::<usize>().is_err())
+        .map(|n| Error::InvalidNumber(Span::new(line, n).on_line(offset + 1))).collect();
+    let boards: Vec<(usize, &str)> = sections.collect();
+    if boards.is_empty() {
+        errors.push(Error::NoBoards);
+    }
+    errors.extend(boards.into_iter().flat_map(|(offset, b)| Board::problems(b).into_iter().map(move |e| e.offset_lines(offset))));
+    errors
+}
+
+pub fn part1(game: &Input) -> Result<Answer, Error> {
+    first_score(&timeline(game, WinRule::Lines))
+}
+
+pub fn part2(game: &Input) -> Result<Answer, Error> {
+    last_score(&timeline(game, WinRule::Lines))
+}
+
+/// The score of the first board to win, from the `wins` given by `timeline`.
+pub fn first_score(wins: &[Win]) -> Result<Answer, Error> {
+    wins.first().map(|w| w.score().into()).ok_or(Error::NoWinner)
+}
+
+/// The score of the last board to win, from the `wins` given by `timeline`.
+pub fn last_score(wins: &[Win]) -> Result<Answer, Error> {
+    wins.last().map(|w| w.score().into()).ok_or(Error::NoWinner)
+}
+
+impl FromStr for Board {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = input::lines(text).collect();
+        let mut rows: Vec<Vec<usize>> = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let mut row = Vec::new();
+            for number in line.split(" ").filter(|n| !n.is_empty()) {
+                row.push(number.parse().map_err(|_| Error::InvalidNumber(Span::new(line, number).on_line(i + 1)))?)
+            }
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(Error::InvalidRowLength { span: Span::line(line).on_line(i + 1), expected: first.len() });
+                }
+            }
+            rows.push(row);
+        }
+        Board::new(rows)
+    }
+}
+
+impl Validate for Board {
+    /// Each number which isn't one, and each row of a different length to the first.
+    fn problems(text: &str) -> Vec<Error> {
+        let rows: Vec<(usize, &str, Vec<&str>)> = input::lines(text).enumerate()
+            .map(|(i, line)| (i, line, line.split(" ").filter(|n| !n.is_empty()).collect())).collect();
+        let expected = rows.first().map_or(0, |(_, _, numbers)| numbers.len());
+        let mut errors = Vec::new();
+        for (i, line, numbers) in rows {
+            errors.extend(numbers.iter().filter(|n| n.parse::<usize>().is_err()).map(|n| Error::InvalidNumber(Span::new(line, n).on_line(i + 1))));
+            if numbers.len() != expected {
+                errors.push(Error::InvalidRowLength { span: Span::line(line).on_line(i + 1), expected });
+            }
+        }
+        errors
+    }
+}
+
+/// Lays out the board's numbers in the same format that `parse` reads, without showing which are marked.
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        for (i, row) in self.numbers.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            let values: Vec<String> = row.iter().map(|n| format!("{:2}", n.value)).collect();
+            write!(f, "{}", values.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Board {
+    /// A board of rows of numbers, which must all be the same length.
+    pub fn new(raw: Vec<Vec<usize>>) -> Result<Self, Error> {
+        let columns = raw.first().map_or(0, |r| r.len());
+        if columns == 0 {
+            return Err(Error::EmptyBoard);
+        }
+        if let Some((row, r)) = raw.iter().enumerate().find(|(_, r)| r.len() != columns) {
+            return Err(Error::UnevenBoard { row, expected: columns, found: r.len() });
+        }
+        Ok(Board {
+            numbers: raw.iter().map(|row| row.iter().map(|&value| Number::new(value)).collect()).collect(),
+            row_hits: vec![0; raw.len()],
+            column_hits: vec![0; columns],
+            diagonal_hits: [0, 0],
+            marked: 0,
+            unmarked: raw.iter().flatten().sum()
+        })
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows() == self.columns()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.numbers.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.column_hits.len()
+    }
+
+    pub fn number(&self, row: usize, column: usize) -> &Number {
+        &self.numbers[row][column]
+    }
+
+    /// Marks every cell with a value.
+    pub fn mark(&mut self, value: usize) {
+        for row in 0..self.rows() {
+            for column in 0..self.columns() {
+                if self.numbers[row][column].value == value {
+                    self.mark_at(row, column);
+                }
+            }
+        }
+    }
+
+    /// Marks the number in a cell, if it isn't already.
+    pub fn mark_at(&mut self, row: usize, column: usize) {
+        let number = &mut self.numbers[row][column];
+        if !number.marked {
+            number.marked = true;
+            self.row_hits[row] += 1;
+            self.column_hits[column] += 1;
+            self.marked += 1;
+            self.unmarked -= number.value;
+            if self.is_square() && row == column {
+                self.diagonal_hits[0] += 1;
+            }
+            if self.is_square() && row + column == self.columns() - 1 {
+                self.diagonal_hits[1] += 1;
+            }
+        }
+    }
+
+    /// The values of the numbers which make the board a winner under a rule, if it is one.
+    pub fn complete(&self, rule: WinRule) -> Option<Vec<usize>> {
+        let values = |cells: &mut dyn Iterator<Item = (usize, usize)>| cells.map(|(r, c)| self.numbers[r][c].value).collect();
+        let (rows, columns) = (self.rows(), self.columns());
+        let lines = || {
+            if let Some(r) = self.row_hits.iter().position(|&hits| hits == columns) {
+                return Some(values(&mut (0..columns).map(|c| (r, c))));
+            }
+            let c = self.column_hits.iter().position(|&hits| hits == rows)?;
+            Some(values(&mut (0..rows).map(|r| (r, c))))
+        };
+        match rule {
+            WinRule::Lines => lines(),
+            WinRule::Diagonals => lines().or_else(|| match self.diagonal_hits {
+                _ if !self.is_square() => None,
+                [hits, _] if hits == rows => Some(values(&mut (0..rows).map(|i| (i, i)))),
+                [_, hits] if hits == rows => Some(values(&mut (0..rows).map(|i| (i, columns - 1 - i)))),
+                _ => None
+            }),
+            WinRule::Corners => {
+                let mut corners = vec![(0, 0), (0, columns - 1), (rows - 1, 0), (rows - 1, columns - 1)];
+                corners.sort();
+                corners.dedup();
+                corners.iter().all(|&(r, c)| self.numbers[r][c].marked).then(|| values(&mut corners.into_iter()))
+            },
+            WinRule::Blackout => (self.marked == rows * columns).then(|| values(&mut (0..rows).flat_map(|r| (0..columns).map(move |c| (r, c)))))
+        }
+    }
+
+    /// The sum of the numbers not yet marked.
+    pub fn unmarked(&self) -> usize {
+        self.unmarked
+    }
+}
+
+impl Number {
+    fn new(value: usize) -> Self {
+        Number {
+            value,
+            marked: false
+        }
+    }
+
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    pub fn marked(&self) -> bool {
+        self.marked
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        if self.marked {
+            write!(f, "[{}]", self.value)?;
+        } else {
+            write!(f, "{}", self.value)?;
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
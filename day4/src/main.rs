@@ -1,178 +1,65 @@
-use std::env;
-use std::fs;
-use std::str::FromStr;
-use std::fmt::Display;
-use std::fmt::Formatter;
-
-struct Board(Vec<Vec<Number>>);
-
-struct Line<'a>(Vec<&'a Number>);
-
-struct Number {
-    value: usize,
-    marked: bool
-}
+use std::time::Instant;
+use aoc_common::{Diagnostic, FileError, Report};
+use aoc_common::input;
+use day4::{first_score, last_score, never_winning, parse, parse_boards, timeline, try_board, Game, Win, WinRule};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut sections = text.split("\r\n\r\n");
-        let calls: Vec<usize> = sections.next().unwrap().split(",").map(|n| n.parse().unwrap()).collect();
-        let mut boards: Vec<Board> = sections.map(|s| s.parse().unwrap()).collect();
-        let mut first = None;
-        let mut last = None;
-        for call in calls {
-            let mut i = 0;
-            while i < boards.len() {
-                let board = &mut boards[i];
-                board.mark(call);
-                if let Some(line) = board.complete() {
-                    if first.is_none() {
-                        first = Some((i, line.to_string(), call, board.unmarked()));
-                    }
-                    last = Some((i, line.to_string(), call, board.unmarked()));
-                    boards.remove(i);
-                } else {
-                    i += 1;
-                }
-            }
+    aoc_common::run(4, &[], &["rule", "candidates"], |text, args, report| {
+        let game = report.parse(|| parse(text))?;
+        let rule = args.option("rule")?.unwrap_or(WinRule::Lines);
+        let candidates: Option<String> = args.option("candidates")?;
+        // Both parts, and everything else reported, come from playing the game through once
+        let start = Instant::now();
+        let wins = timeline(&game, rule);
+        report.time("timeline", start.elapsed());
+        report.part(1, || first_score(&wins));
+        report.part(2, || last_score(&wins));
+        if let (Some(first), Some(last)) = (wins.first(), wins.last()) {
+            report_win(report, "first", first);
+            report_win(report, "last", last);
         }
-        print_result("FIRST", first.unwrap());
-        print_result("LAST", last.unwrap());
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
-}
-
-fn print_result(description: &str, r: (usize, String, usize, usize)) {
-    println!("Board #{} wins {}, completing line {} with {}, leaving Σ{} unmarked with a score of: {}", r.0+1, description, r.1, r.2, r.3, r.2*r.3);
-}
-
-impl FromStr for Board {
-    type Err = String;
-
-    fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let mut rows = Vec::new();
-        for line in text.lines() {
-            let mut row = Vec::new();
-            for number in line.split(" ").filter(|n| !n.is_empty()) {
-                row.push(number.parse().unwrap())
-            }
-            rows.push(row);
-        }
-        Ok(Board::new(rows))
-    }
-}
-
-impl Board {
-    fn new(raw: Vec<Vec<usize>>) -> Self {
-        let mut numbers = Vec::new();
-        for r in 0..raw.len() {
-            let mut row = Vec::new();
-            for c in 0..raw[0].len() {
-                let number = Number::new(raw[r][c]);
-                row.push(number);
-            }
-            numbers.push(row);
-        }
-        Board(numbers)
-    }
-
-    fn rows(&self) -> Vec<Line> {
-        let mut rows = Vec::new();
-        for r in 0..self.0.len() {
-            let mut row = Vec::new();
-            for c in 0..self.0[0].len() {
-                row.push(&self.0[r][c]);
-            }
-            rows.push(Line(row))
-        }
-        rows
-    }
-
-    fn columns(&self) -> Vec<Line> {
-        let mut columns = Vec::new();
-        for c in 0..self.0[0].len() {
-            let mut column = Vec::new();
-            for r in 0..self.0.len() {
-                column.push(&self.0[r][c]);
-            }
-            columns.push(Line(column))
-        }
-        columns
-    }
-
-    fn mark(&mut self, value: usize) {
-        for row in self.0.iter_mut() {
-            for number in row.iter_mut() {
-                if number.value == value {
-                    number.marked = true;
-                }
-            }
-        }
-    }
-
-    fn complete(&self) -> Option<Line> {
-        for row in self.rows() {
-            if row.complete() {
-                return Some(row);
-            }
-        }
-        for column in self.columns() {
-            if column.complete() {
-                return Some(column);
-            }
-        }
-        None
-    }
-
-    fn unmarked(&self) -> usize {
-        self.0.iter().flatten().filter(|n| !n.marked).map(|n| n.value).sum()
-    }
-}
-
-impl Number {
-    fn new(value: usize) -> Self {
-        Number {
-            value,
-            marked: false
-        }
-    }
-}
-
-impl Display for Number {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        if self.marked {
-            write!(f, "[{}]", self.value)?;
-        } else {
-            write!(f, "{}", self.value)?;
+        report.value("winning_boards", wins.len());
+        let lines: Vec<String> = wins.iter()
+            .map(|w| format!("board {} on call {} ({}), scoring {}", w.board + 1, w.turn + 1, w.call, w.score()))
+            .collect();
+        report.value("timeline", format!("\n{}", lines.join("\n")));
+        let never: Vec<String> = never_winning(&game, &wins).iter().map(|b| (b + 1).to_string()).collect();
+        report.value("never_winning_boards", if never.is_empty() { "none".to_string() } else { never.join(",") });
+        if let Some(path) = candidates {
+            report_candidates(report, &game, &wins, rule, &path)?;
         }
         Ok(())
-    }
+    });
 }
 
-impl Line<'_> {
-    fn complete(&self) -> bool {
-        for n in &self.0 {
-            if !n.marked {
-                return false;
-            }
-        }
-        true
-    }
+fn report_win(report: &mut Report, description: &str, win: &Win) {
+    let numbers: Vec<String> = win.numbers.iter().map(|n| format!("[{}]", n)).collect();
+    report.value(&format!("{}_winning_board", description), win.board + 1);
+    report.value(&format!("{}_winning_line", description), numbers.join(","));
+    report.value(&format!("{}_winning_call", description), win.call);
+    report.value(&format!("{}_unmarked_sum", description), win.unmarked);
 }
 
-impl Display for Line<'_> {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
-        for (i, n) in self.0.iter().enumerate() {
-            if i != 0 {
-                write!(f, ",")?;
-            }
-            write!(f, "{}", n)?;
+/// Scores each board in a file of candidates against the game's calls. A file which can't be read or
+/// doesn't parse fails the run, with any problem reported against the candidates file's own lines.
+fn report_candidates(report: &mut Report, game: &Game, wins: &[Win], rule: WinRule, path: &str) -> Result<(), Box<dyn Diagnostic>> {
+    let name = input::name(path);
+    let text = input::read(path).map_err(|e| format!("Error reading from {}: {}", name, e))?;
+    let boards = match parse_boards(&text) {
+        Ok(boards) => boards,
+        Err(e) => return Err(FileError::new(e, name, text).into())
+    };
+    for (i, board) in boards.iter().enumerate() {
+        let description = format!("candidate_{}", i + 1);
+        match try_board(game, wins, board, rule) {
+            Some(candidate) => {
+                report.value(&format!("{}_winning_call", description), candidate.win.call);
+                report.value(&format!("{}_winning_turn", description), candidate.win.turn + 1);
+                report.value(&format!("{}_score", description), candidate.win.score());
+                report.value(&format!("{}_beaten_by", description), candidate.beaten_by);
+            },
+            None => report.value(&format!("{}_winning_call", description), "never")
         }
-        Ok(())
     }
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// What a board needs marked to win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinRule {
+    /// A whole row or column, as in the puzzle
+    Lines,
+    /// A whole row, column or, on a square board, either diagonal
+    Diagonals,
+    /// All four corners
+    Corners,
+    /// Every number on the board
+    Blackout
+}
+
+impl WinRule {
+    pub const ALL: [WinRule; 4] = [WinRule::Lines, WinRule::Diagonals, WinRule::Corners, WinRule::Blackout];
+}
+
+impl Display for WinRule {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        let name = match self {
+            WinRule::Lines => "lines",
+            WinRule::Diagonals => "diagonals",
+            WinRule::Corners => "corners",
+            WinRule::Blackout => "blackout"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for WinRule {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        WinRule::ALL.into_iter().find(|r| r.to_string() == text)
+            .ok_or(format!("Invalid rule: {}, expected lines, diagonals, corners or blackout", text))
+    }
+}
\ No newline at end of file
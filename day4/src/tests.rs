@@ -0,0 +1,148 @@
+use super::*;
+use aoc_common::assert_round_trip;
+
+#[test]
+fn board_round_trip() {
+    let game = parse(&generate::generate(0, 10)).unwrap();
+    assert_round_trip(game.boards.len() as u64, |i| game.boards[i as usize].clone());
+}
+
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("7,x,9,y\n\n1 2\n3 4\n\n1 2 3\n4 5 6");
+    assert_eq!(errors.len(), 2);
+    let errors = validate("7,8\n\n1 x\n3 y 5");
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], Error::InvalidNumber(span) if span.line == 3 && span.text == "x"));
+    assert!(matches!(&errors[1], Error::InvalidNumber(span) if span.line == 4 && span.text == "y"));
+    assert!(matches!(&errors[2], Error::InvalidRowLength { span, expected: 2 } if span.line == 4));
+    assert!(matches!(&errors[0], Error::InvalidNumber(span) if span.text == "x"));
+    assert!(validate("7,8\n\n1 2\n3").iter().any(|e| matches!(e, Error::InvalidRowLength { expected: 2, .. })));
+    assert_eq!(validate("7,8,9"), vec![Error::NoBoards]);
+    assert!(validate(&generate::generate(0, 5)).is_empty());
+}
+
+#[test]
+fn timeline_orders_every_win() {
+    let game = parse(&generate::generate(1, 2000)).unwrap();
+    let wins = timeline(&game, WinRule::Lines);
+    assert_eq!(wins.len(), 2000);
+    assert!(wins.windows(2).all(|w| w[0].turn < w[1].turn || (w[0].turn == w[1].turn && w[0].board < w[1].board)));
+    assert_eq!(part1(&game), first_score(&wins));
+    assert_eq!(part2(&game), last_score(&wins));
+    let mut board = game.boards[wins[0].board].clone();
+    for &call in &game.calls[..=wins[0].turn] {
+        board.mark(call);
+    }
+    assert_eq!(board.complete(WinRule::Lines), Some(wins[0].numbers.clone()));
+    assert_eq!(board.unmarked() * wins[0].call, wins[0].score());
+}
+
+/// Marks calls on a board in turn, giving the first call it wins on under a rule.
+fn winning_call(board: &str, calls: &[usize], rule: WinRule) -> Option<usize> {
+    let mut board: Board = board.parse().unwrap();
+    calls.iter().copied().find(|&call| {
+        board.mark(call);
+        board.complete(rule).is_some()
+    })
+}
+
+const BOARD: &str = " 1  2  3\n 4  5  6\n 7  8  9";
+
+const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+/// The board, call and score of the first and last boards to win the example under a rule.
+fn example_winners(rule: WinRule) -> ((usize, usize, usize), (usize, usize, usize)) {
+    let wins = timeline(&parse(EXAMPLE).unwrap(), rule);
+    let summary = |w: Option<&Win>| w.map(|w| (w.board, w.call, w.score())).unwrap();
+    (summary(wins.first()), summary(wins.last()))
+}
+
+#[test]
+fn rule_lines() {
+    assert_eq!(winning_call(BOARD, &[1, 5, 9, 4, 7], WinRule::Lines), Some(7));
+    assert_eq!(winning_call(BOARD, &[3, 5, 7, 2, 8], WinRule::Lines), Some(8));
+    assert_eq!(example_winners(WinRule::Lines), ((2, 24, 4512), (1, 13, 1924)));
+}
+
+#[test]
+fn rule_diagonals() {
+    assert_eq!(winning_call(BOARD, &[1, 5, 9, 4, 7], WinRule::Diagonals), Some(9));
+    assert_eq!(winning_call(BOARD, &[3, 5, 7, 2, 8], WinRule::Diagonals), Some(7));
+    assert_eq!(winning_call("1 2 3\n4 5 6", &[1, 5, 6, 4], WinRule::Diagonals), Some(4));
+    assert_eq!(example_winners(WinRule::Diagonals), ((2, 2, 494), (1, 13, 1924)));
+}
+
+#[test]
+fn rule_corners() {
+    assert_eq!(winning_call(BOARD, &[1, 2, 3, 7, 5, 9], WinRule::Corners), Some(9));
+    assert_eq!(winning_call("1 2 3\n4 5 6", &[1, 3, 4, 6], WinRule::Corners), Some(6));
+    assert_eq!(winning_call("1 2 3", &[1, 2, 3], WinRule::Corners), Some(3));
+    let mut row: Board = "1 2 3".parse().unwrap();
+    row.mark(1);
+    row.mark(3);
+    assert_eq!(row.complete(WinRule::Corners), Some(vec![1, 3]));
+    assert_eq!(example_winners(WinRule::Corners), ((2, 14, 3262), (0, 1, 0)));
+}
+
+#[test]
+fn winners_depend_on_the_rule() {
+    let game = parse("1,3,7,9\n\n1 2 3\n4 5 6\n7 8 9").unwrap();
+    assert_eq!(part1(&game), Err(Error::NoWinner));
+    assert_eq!(first_score(&timeline(&game, WinRule::Corners)), Ok(Answer::from(225)));
+}
+
+#[test]
+fn rule_blackout() {
+    let calls = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+    assert_eq!(winning_call(BOARD, &calls, WinRule::Blackout), Some(1));
+    assert_eq!(winning_call(BOARD, &calls[..8], WinRule::Blackout), None);
+    assert_eq!(example_winners(WinRule::Blackout), ((1, 3, 0), (0, 1, 0)));
+}
+
+#[test]
+fn boards_need_even_rows() {
+    assert!(matches!(Board::new(vec![vec![1, 2], vec![3]]), Err(Error::UnevenBoard { row: 1, expected: 2, found: 1 })));
+    assert!(matches!(Board::new(vec![]), Err(Error::EmptyBoard)));
+    assert_eq!(Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap().to_string(), " 1  2  3\n 4  5  6");
+}
+
+#[test]
+fn analysis_of_boards() {
+    let game = parse("3,1,2\n\n1 2\n3 4\n\n5 6\n7 8\n\n2 9\n1 9").unwrap();
+    let timeline = timeline(&game, WinRule::Lines);
+    assert_eq!(never_winning(&game, &timeline), vec![1]);
+    let wins = earliest_wins(&game, &timeline);
+    assert_eq!(wins[0].map(|w| (w.turn, w.call, w.score())), Some((1, 1, 6)));
+    assert_eq!(wins[1], None);
+    let candidate = try_board(&game, &timeline, &"9 3\n9 2".parse().unwrap(), WinRule::Lines).unwrap();
+    assert_eq!((candidate.win.board, candidate.win.turn, candidate.win.score(), candidate.beaten_by), (3, 2, 36, 1));
+    assert_eq!(try_board(&game, &timeline, &"7 8".parse().unwrap(), WinRule::Lines), None);
+}
+
+#[test]
+fn candidate_boards_parse_on_their_own() {
+    let boards = parse_boards("1 2\n3 4\n\n5 6 7").unwrap();
+    assert_eq!((boards.len(), boards[1].columns()), (2, 3));
+    let error = parse_boards("1 2\n3 4\n\n5 6\n7 x").err();
+    assert!(matches!(&error, Some(Error::InvalidNumber(span)) if span.text == "x" && span.line == 5));
+    assert_eq!(parse_boards("\n\n").err(), Some(Error::NoBoards));
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't two points separated by an arrow
+    InvalidLine(Span),
+    /// A point which isn't two whole numbers separated by a comma
+    InvalidPoint(Span)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidLine(span) => write!(f, "expected `<x>,<y> -> <x>,<y>`, found `{}`", span.text),
+            Error::InvalidPoint(span) => write!(f, "expected `<x>,<y>`, found `{}`", span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidLine(span) | Error::InvalidPoint(span) => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidLine(span) | Error::InvalidPoint(span) => Some(span)
+        }
+    }
+}
\ No newline at end of file
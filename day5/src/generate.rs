@@ -0,0 +1,16 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 500;
+
+/// `size` horizontal, vertical and 45 degree diagonal lines within a 1000x1000 area.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let (x1, y1) = (rng.between(10, 989), rng.between(10, 989));
+        let length = rng.between(1, 500).min(989 - x1).min(989 - y1).min(x1 - 10).min(y1 - 10).max(1);
+        let (dx, dy) = *rng.choose(&[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)]);
+        lines.push(format!("{},{} -> {},{}", x1, y1, x1 + dx * length, y1 + dy * length));
+    }
+    lines.join("\n")
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use std::str::FromStr;
+use std::collections::HashSet;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Point {
+    x: isize,
+    y: isize
+}
+
+pub struct Line {
+    from: Point,
+    to: Point
+}
+
+pub type Input = Vec<Line>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing each point which isn't valid and each line which isn't two
+/// points joined by an arrow.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Line, Error>(text)
+}
+
+pub fn part1(lines: &Input) -> Answer {
+    let simple: Vec<&Line> = lines.iter().filter(|l| l.horizontal() || l.vertical()).collect();
+    find_overlaps(&simple).len().into()
+}
+
+pub fn part2(lines: &Input) -> Answer {
+    let all: Vec<&Line> = lines.iter().collect();
+    find_overlaps(&all).len().into()
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let points: Vec<Point> = line.split(" -> ").map(|p| p.parse()
+            .map_err(|_| Error::InvalidPoint(Span::new(line, p)))).collect::<Result<_, _>>()?;
+        if points.len() == 2 {
+            Ok(Line {
+                from: points[0],
+                to: points[1]
+            })
+        } else {
+            Err(Error::InvalidLine(Span::line(line)))
+        }
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(point: &str) -> Result<Self, Self::Err> {
+        let values: Vec<isize> = point.split(",").map(|v| v.parse()
+            .map_err(|_| Error::InvalidPoint(Span::line(point)))).collect::<Result<_, _>>()?;
+        if values.len() == 2 {
+            Ok(Point {
+                x: values[0],
+                y: values[1]
+            })
+        } else {
+            Err(Error::InvalidPoint(Span::line(point)))
+        }
+    }
+}
+
+impl Validate for Line {
+    fn problems(line: &str) -> Vec<Error> {
+        let points: Vec<&str> = line.split(" -> ").collect();
+        let mut errors: Vec<Error> = points.iter().filter(|p| p.parse::<Point>().is_err()).map(|p| Error::InvalidPoint(Span::new(line, p))).collect();
+        if points.len() != 2 {
+            errors.push(Error::InvalidLine(Span::line(line)));
+        }
+        errors
+    }
+}
+
+impl Line {
+    pub fn horizontal(&self) -> bool {
+        self.from.x == self.to.x
+    }
+
+    pub fn vertical(&self) -> bool {
+        self.from.y == self.to.y
+    }
+
+    fn points(&self) -> Vec<Point> {
+        let mut points = Vec::new();
+        let (dx, dy) = if self.horizontal() {
+            (0, (self.to.y-self.from.y).signum())
+        } else if self.vertical() {
+            ((self.to.x-self.from.x).signum(), 0)
+        } else {
+            ((self.to.x-self.from.x).signum(), (self.to.y-self.from.y).signum())
+        };
+        let mut p = self.from;
+        loop {
+            points.push(p);
+            if p == self.to {
+                break;
+            }
+            p.x += dx;
+            p.y += dy;
+        }
+        points
+    }
+}
+
+pub fn find_overlaps(lines: &[&Line]) -> HashSet<Point> {
+    let mut taken = HashSet::new();
+    let mut overlaps = HashSet::new();
+    for line in lines {
+        for point in line.points() {
+            if !taken.insert(point) {
+                overlaps.insert(point);
+            }
+        }
+    }
+    overlaps
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use super::*;
+
+#[test]
+fn validate_lists_every_problem() {
+    let errors = validate("0,9 -> 5,9\nx,1 -> 2,y\n1,1 -> 2,2 -> 3,3");
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], Error::InvalidPoint(span) if span.line == 2 && span.text == "x,1"));
+    assert!(matches!(&errors[1], Error::InvalidPoint(span) if span.line == 2 && span.column == 8));
+    assert!(matches!(&errors[2], Error::InvalidLine(span) if span.line == 3));
+}
@@ -0,0 +1,9 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 300;
+
+/// `size` lanternfish, with timers from 1 to 5 like the real input.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    (0..size).map(|_| rng.between(1, 5).to_string()).collect::<Vec<_>>().join(",")
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use aoc_common::{Answer, Span};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+/// A map from fish countdown number to the count of fish at that number.
+pub type Input = HashMap<isize, usize>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let line = text.trim();
+    let numbers: Vec<isize> = line.split(",").map(|n| n.parse()
+        .map_err(|_| Error::InvalidTimer(Span::new(line, n)))).collect::<Result<_, _>>()?;
+    let mut fish: HashMap<isize, usize> = HashMap::new(); // map from fish countdown number to count at that number
+    for n in numbers {
+        add_value(&mut fish, n, 1);
+    }
+    Ok(fish)
+}
+
+/// Checks the list of timers, listing every one which isn't a number.
+pub fn validate(text: &str) -> Vec<Error> {
+    let line = text.trim();
+    line.split(",").filter(|n| n.parse::<isize>().is_err()).map(|n| Error::InvalidTimer(Span::new(line, n))).collect()
+}
+
+pub fn part1(fish: &Input) -> Answer {
+    count_after(fish, 80).into()
+}
+
+pub fn part2(fish: &Input) -> Answer {
+    count_after(fish, 256).into()
+}
+
+pub fn count_after(fish: &HashMap<isize, usize>, days: usize) -> usize {
+    let mut fish = fish.clone();
+    for _ in 0..days {
+        fish = simulate(&fish);
+    }
+    fish.values().sum()
+}
+
+pub fn simulate(fish: &HashMap<isize, usize>) -> HashMap<isize, usize> {
+    let mut new_fish = HashMap::new();
+    for (old_fish, count) in fish {
+        if *old_fish == 0 {
+            add_value(&mut new_fish, 8, *count);
+            add_value(&mut new_fish, 6, *count);
+        } else {
+            add_value(&mut new_fish, old_fish - 1, *count);
+        }
+    }
+    new_fish
+}
+
+fn add_value(map: &mut HashMap<isize, usize>, key: isize, delta: usize) {
+    if let Some(existing) = map.get(&key) {
+        map.insert(key, existing + delta);
+    } else {
+        map.insert(key, delta);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` crab positions, mostly small with a long tail of larger ones.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    (0..size).map(|_| {
+        let scale = *rng.choose(&[10, 100, 1000, 2000]);
+        rng.between(0, scale).to_string()
+    }).collect::<Vec<_>>().join(",")
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use aoc_common::{Answer, Span};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+/// Crab positions, in ascending order.
+pub type Input = Vec<isize>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let line = text.trim();
+    let mut numbers: Vec<isize> = line.split(",").map(|n| n.parse()
+        .map_err(|_| Error::InvalidPosition(Span::new(line, n)))).collect::<Result<_, _>>()?;
+    numbers.sort();
+    Ok(numbers)
+}
+
+/// Checks the list of positions, listing every one which isn't a number.
+pub fn validate(text: &str) -> Vec<Error> {
+    let line = text.trim();
+    line.split(",").filter(|n| n.parse::<isize>().is_err()).map(|n| Error::InvalidPosition(Span::new(line, n))).collect()
+}
+
+pub fn part1(positions: &Input) -> Answer {
+    basic_fuel(positions).into()
+}
+
+pub fn part2(positions: &Input) -> Answer {
+    complex_fuel(positions).into()
+}
+
+pub fn basic_fuel(numbers: &[isize]) -> isize {
+    let median = median(numbers);
+    numbers.iter().map(|n| (*n-median as isize).abs()).sum()
+}
+
+pub fn complex_fuel(numbers: &[isize]) -> isize {
+    let min = numbers[0];
+    let max = numbers[numbers.len()-1];
+    let mut fuel_options = Vec::new();
+    for move_to in min..(max+1) {
+        let fuel: isize = numbers.iter().map(|n| fuel_cost((*n-move_to).abs())).sum();
+        fuel_options.push(fuel);
+    }
+    *fuel_options.iter().min().unwrap()
+}
+
+fn median(array: &[isize])->f64{
+    if array.len().is_multiple_of(2) {
+        let ind_left = array.len()/2-1; 
+        let ind_right = array.len()/2 ;
+        (array[ind_left]+array[ind_right]) as f64 / 2.0
+
+    } else {
+            array[array.len()/2] as f64
+    }
+}
+
+fn fuel_cost(distance: isize) -> isize {
+    distance*(distance+1)/2
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line which isn't unique signals and output separated by a bar
+    InvalidEntry(Span),
+    /// A signal which isn't one or more distinct segments from a to g
+    InvalidSignal(Span),
+    /// A list of signals of the wrong length
+    WrongSignalCount { span: Span, expected: usize }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidEntry(span) => write!(f, "expected `<10 signals> | <4 signals>`, found `{}`", span.text),
+            Error::InvalidSignal(span) => write!(f, "expected distinct segments `a` to `g`, found `{}`", span.text),
+            Error::WrongSignalCount { span, expected } => write!(f, "expected {} signals, found `{}`", expected, span.text)
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidEntry(span) | Error::InvalidSignal(span) => Some(span),
+            Error::WrongSignalCount { span, .. } => Some(span)
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidEntry(span) | Error::InvalidSignal(span) => Some(span),
+            Error::WrongSignalCount { span, .. } => Some(span)
+        }
+    }
+}
\ No newline at end of file
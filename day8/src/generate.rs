@@ -0,0 +1,30 @@
+use aoc_common::Rng;
+
+pub const DEFAULT_SIZE: usize = 200;
+
+/// The segments lit for each digit from 0 to 9, on correctly wired displays.
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+/// `size` displays, each with its wires randomly crossed, listing all ten digits in a random order then
+/// four output digits.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wiring);
+        let mut signals: Vec<String> = DIGITS.iter().map(|digit| {
+            let mut signal: Vec<char> = digit.chars().map(|s| wiring[s as usize - 'a' as usize]).collect();
+            rng.shuffle(&mut signal);
+            signal.into_iter().collect()
+        }).collect();
+        let output: Vec<String> = (0..4).map(|_| {
+            let mut signal: Vec<char> = rng.choose(&signals).chars().collect();
+            rng.shuffle(&mut signal);
+            signal.into_iter().collect()
+        }).collect();
+        rng.shuffle(&mut signals);
+        lines.push(format!("{} | {}", signals.join(" "), output.join(" ")));
+    }
+    lines.join("\n")
+}
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::str::FromStr;
+use itertools::Itertools;
+use std::collections::HashSet;
+use aoc_common::{Answer, Span};
+use aoc_common::input;
+use aoc_common::input::Validate;
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+#[cfg(test)]
+mod tests;
+
+pub struct Entry {
+    unique: [Signal; 10],
+    output: [Signal; 4]
+}
+
+#[derive(Hash, Debug, PartialEq, Eq)]
+pub struct Signal([bool; 7]);
+
+struct WireMap([usize; 7]);
+
+pub type Input = Vec<Entry>;
+
+pub fn parse(text: &str) -> Result<Input, Error> {
+    input::parse_lines(text)
+}
+
+/// Checks every line of the input, listing every problem with each entry of signals and output rather
+/// than only the first.
+pub fn validate(text: &str) -> Vec<Error> {
+    input::validate_lines::<Entry, Error>(text)
+}
+
+pub fn part1(entries: &Input) -> Answer {
+    count_simple_digits(entries).into()
+}
+
+pub fn part2(entries: &Input) -> Answer {
+    let digits = digits();
+    entries.iter().map(|e| e.decode(&digits)).sum::<usize>().into()
+}
+
+pub fn count_simple_digits(entries: &[Entry]) -> usize {
+    entries.iter().flat_map(|e| e.output.iter()).filter(|s| match s.segments().len() {
+        2 => true, // "1"
+        4 => true, // "4"
+        3 => true, // "7"
+        7 => true, // "8"
+        _ => false
+    }).count()
+}
+
+/// The segments lit for each digit 0-9 on a correctly wired display.
+pub fn digits() -> [Signal; 10] {
+    [
+        Signal::from_str("abcefg").unwrap(),
+        Signal::from_str("cf").unwrap(),
+        Signal::from_str("acdeg").unwrap(),
+        Signal::from_str("acdfg").unwrap(),
+        Signal::from_str("bdcf").unwrap(),
+        Signal::from_str("abdfg").unwrap(),
+        Signal::from_str("abdefg").unwrap(),
+        Signal::from_str("acf").unwrap(),
+        Signal::from_str("abcdefg").unwrap(),
+        Signal::from_str("abcdfg").unwrap()
+    ]
+}
+
+impl Entry {
+    pub fn decode(&self, digits: &[Signal; 10]) -> usize {
+        let wire_map = WireMap::new(&self.unique, digits);
+        let mut output = 0;
+        for raw in &self.output {
+            let decoded = raw.decode(&wire_map);
+            let number = digits.iter().position(|d| *d == decoded).unwrap();
+            output *= 10;
+            output += number;
+        }
+        output
+    }
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split(" | ").collect();
+        if parts.len() != 2 {
+            return Err(Error::InvalidEntry(Span::line(line)));
+        }
+        let parse_signal = |s: &str| s.parse().map_err(|_| Error::InvalidSignal(Span::new(line, s)));
+        let unique: Vec<Signal> = parts[0].split(" ").map(parse_signal).collect::<Result<_, _>>()?;
+        if unique.len() != 10 {
+            return Err(Error::WrongSignalCount { span: Span::new(line, parts[0]), expected: 10 });
+        }
+        let output: Vec<Signal> = parts[1].split(" ").map(parse_signal).collect::<Result<_, _>>()?;
+        if output.len() != 4 {
+            return Err(Error::WrongSignalCount { span: Span::new(line, parts[1]), expected: 4 });
+        }
+        Ok(Entry {
+            unique: unique.try_into().unwrap(),
+            output: output.try_into().unwrap()
+        })
+    }
+}
+
+impl Validate for Entry {
+    /// Each invalid signal, and each list with the wrong number of signals.
+    fn problems(line: &str) -> Vec<Error> {
+        let parts: Vec<&str> = line.split(" | ").collect();
+        if parts.len() != 2 {
+            return vec![Error::InvalidEntry(Span::line(line))];
+        }
+        let mut errors = Vec::new();
+        for (part, expected) in parts.into_iter().zip([10, 4]) {
+            let signals: Vec<&str> = part.split(" ").collect();
+            errors.extend(signals.iter().filter(|s| s.parse::<Signal>().is_err()).map(|s| Error::InvalidSignal(Span::new(line, s))));
+            if signals.len() != expected {
+                errors.push(Error::WrongSignalCount { span: Span::new(line, part), expected });
+            }
+        }
+        errors
+    }
+}
+
+impl FromStr for Signal {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut signal = [false; 7];
+        for c in string.chars() {
+            if ('a'..='g').contains(&c) && !signal[(c as u8 - b'a') as usize] {
+                signal[(c as u8 - b'a') as usize] = true;
+            } else {
+                return Err(Error::InvalidSignal(Span::line(string)));
+            }
+        }
+        if string.is_empty() {
+            return Err(Error::InvalidSignal(Span::line(string)));
+        }
+        Ok(Signal(signal))
+    }
+}
+
+impl Signal {
+    fn decode(&self, map: &WireMap) -> Signal {
+        let mut signal = [false; 7];
+        for (i, s) in signal.iter_mut().enumerate() {
+            let pos = map.0.iter().position(|o| *o == i).unwrap();
+            *s = self.0[pos];
+        }
+        Signal(signal)
+    }
+
+    fn segments(&self) -> Vec<usize> {
+        let mut seggs = Vec::new();
+        for i in 0..7 {
+            if self.0[i] {
+                seggs.push(i);
+            }
+        }
+        seggs
+    }
+}
+
+impl WireMap {
+    fn new<const N: usize>(input_signals: &[Signal; N], output_signals: &[Signal; N]) -> Self {
+        for map in Self::enumerate() {
+            let mut remaining_outputs: HashSet<&Signal> = output_signals.iter().collect();
+            let mut valid = true;
+            for input in input_signals {
+                let output = input.decode(&map);
+                if !remaining_outputs.remove(&output) {
+                    valid = false;
+                    break;
+                }
+            }
+            if valid {
+                return map;
+            }
+        }
+        panic!("No valid map found");
+    }
+
+    fn enumerate() -> Vec<Self> {
+        [0,1,2,3,4,5,6].iter().permutations(7).map(|m| WireMap(m.iter().map(|v| **v).collect::<Vec<usize>>().try_into().unwrap())).collect()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use super::*;
+
+#[test]
+fn validate_lists_every_problem_on_a_line() {
+    let errors = validate("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fdgacbe ced fdcbe | fdgacbe cefdb cefbgd gcbe\nab xy cd | ab ab ab\nab cd");
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(&errors[0], Error::InvalidSignal(span) if span.line == 2 && span.column == 4 && span.text == "xy"));
+    assert!(matches!(&errors[1], Error::WrongSignalCount { span, expected: 10 } if span.line == 2 && span.text == "ab xy cd"));
+    assert!(matches!(&errors[2], Error::WrongSignalCount { span, expected: 4 } if span.line == 2 && span.column == 12));
+    assert!(matches!(&errors[3], Error::InvalidEntry(span) if span.line == 3));
+    assert!(validate(&generate::generate(0, 5)).is_empty());
+}
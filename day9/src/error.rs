@@ -0,0 +1,54 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use aoc_common::{Diagnostic, GridError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A height which isn't a single digit
+    InvalidHeight(Span),
+    /// A row with a different number of values to the first row
+    InvalidRowLength { span: Span, expected: usize },
+    /// Input without any rows
+    EmptyMap,
+    /// A map with fewer than three basins
+    TooFewBasins
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::InvalidHeight(span) => write!(f, "expected a height digit, found `{}`", span.text),
+            Error::InvalidRowLength { span, expected } => write!(f, "expected {} values in row, found `{}`", expected, span.text),
+            Error::EmptyMap => write!(f, "expected at least one row of heights"),
+            Error::TooFewBasins => write!(f, "expected at least 3 basins")
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Error::InvalidHeight(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+
+    fn span_mut(&mut self) -> Option<&mut Span> {
+        match self {
+            Error::InvalidHeight(span) => Some(span),
+            Error::InvalidRowLength { span, .. } => Some(span),
+            _ => None
+        }
+    }
+}
+
+impl From<GridError> for Error {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::InvalidCell(span) => Error::InvalidHeight(span),
+            GridError::InvalidRowLength { span, expected } => Error::InvalidRowLength { span, expected },
+            GridError::Empty => Error::EmptyMap
+        }
+    }
+}
\ No newline at end of file
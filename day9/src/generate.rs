@@ -0,0 +1,19 @@
+use aoc_common::{Grid, Rng};
+use crate::Map;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A `size` by `size` height map (at least 4 by 4) of basins walled in by ridges of 9, redrawn until
+/// it has the three basins part 2 needs.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(4);
+    loop {
+        let heights = Grid::from_fn(size, size, |_| if rng.chance(0.3) { 9 } else { rng.between(0, 8) as u32 });
+        let text = heights.to_string();
+        let map = Map { heights };
+        if map.find_low_points().len() >= 3 {
+            return text;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use std::str::FromStr;
+use std::collections::HashSet;
+use aoc_common::{Answer, Grid, Position};
+
+pub mod generate;
+mod error;
+
+pub use error::Error;
+
+pub struct Map {
+    heights: Grid<u32>
+}
+
+pub struct Basin(HashSet<Position>);
+
+pub type Input = Map;
+
+/// Parses a height map, checking that it has at least the three basins needed by part 2.
+pub fn parse(text: &str) -> Result<Input, Error> {
+    let map: Map = text.parse()?;
+    if map.find_low_points().len() < 3 {
+        return Err(Error::TooFewBasins);
+    }
+    Ok(map)
+}
+
+/// Checks the map without looking for basins, listing every height which isn't a digit and every row
+/// of the wrong length.
+pub fn validate(text: &str) -> Vec<Error> {
+    Grid::validate(text, |h| h.to_digit(10)).into_iter().map(Error::from).collect()
+}
+
+pub fn part1(map: &Input) -> Answer {
+    map.risk_level().into()
+}
+
+pub fn part2(map: &Input) -> Answer {
+    let sizes = map.basin_sizes();
+    (sizes[0]*sizes[1]*sizes[2]).into()
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse(text, |h| h.to_digit(10))?;
+        Ok(Map { heights })
+    }
+}
+
+impl Map {
+    /// The sum of the risk levels (height + 1) of every low point.
+    pub fn risk_level(&self) -> u32 {
+        self.find_low_points().into_iter().map(|p| self.heights[p] + 1).sum()
+    }
+
+    /// The size of the basin around every low point, largest first.
+    pub fn basin_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.find_low_points().into_iter().map(|p| self.find_basin(p).size()).collect();
+        sizes.sort();
+        sizes.reverse();
+        sizes
+    }
+
+    pub fn find_low_points(&self) -> Vec<Position> {
+        self.heights.positions().filter(|&p| {
+            let v = self.heights[p];
+            self.heights.neighbours4(p).all(|a| v < self.heights[a])
+        }).collect()
+    }
+
+    fn find_basin(&self, point: Position) -> Basin {
+        let mut points = HashSet::new();
+        self.expand_basin(&mut points, point);
+        Basin(points)
+    }
+
+    fn expand_basin(&self, points: &mut HashSet<Position>, p: Position) {
+        let v = self.heights[p];
+        if points.insert(p) {
+            for a in self.heights.neighbours4(p) {
+                let a_v = self.heights[a];
+                if a_v > v && a_v != 9 {
+                    self.expand_basin(points, a);
+                }
+            }
+        }
+    }
+}
+
+impl Basin {
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+use crate::days::Day;
+
+/// The outcome of checking one part of a day against one input file.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, found: String },
+    Missing { found: String }
+}
+
+/// The input file each day's answers are recorded for, in its directory. Answers to the examples are
+/// recorded in each day's `expected.toml` instead, and checked by the tests.
+pub const INPUT: &str = "input.txt";
+
+/// Solves each part a day has a solution for against some input, giving each answer or error message.
+pub fn solve(day: &Day, text: &str) -> Vec<(usize, Result<String, String>)> {
+    day.parts.iter().filter_map(|&part| {
+        let solve = day.part(part)?;
+        Some((part, solve(text).map(|a| a.to_string()).map_err(|e| e.to_string())))
+    }).collect()
+}
+
+/// Reads an answers file, which has a table of answers for each day's input file:
+///
+/// ```text
+/// [day1."input.txt"]
+/// part1 = 1655
+/// part2 = 1683
+/// ```
+///
+/// A missing file has no answers.
+pub fn load(path: &Path) -> Result<Table, String> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+    let text = fs::read_to_string(path).map_err(|e| format!("Error reading from {}: {}", path.display(), e))?;
+    text.parse().map_err(|e| format!("Error parsing {}: {}", path.display(), e))
+}
+
+pub fn save(path: &Path, answers: &Table) -> Result<(), String> {
+    let text = format!("# Answers for each day's input.txt, checked by `advent2021 check` and written by `advent2021 record`\n\n{}", answers);
+    fs::write(path, text).map_err(|e| format!("Error writing to {}: {}", path.display(), e))
+}
+
+/// The recorded answer to a part for an input file, if there is one.
+pub fn expected(answers: &Table, day: usize, filename: &str, part: usize) -> Option<String> {
+    match answers.get(&format!("day{}", day))?.get(filename)?.get(format!("part{}", part))? {
+        Value::Integer(i) => Some(i.to_string()),
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string())
+    }
+}
+
+/// Replaces the recorded answer to a part for an input file. Numbers are kept as TOML integers where they fit.
+pub fn record(answers: &mut Table, day: usize, filename: &str, part: usize, answer: &str) {
+    let value = match answer.parse::<i64>() {
+        Ok(i) if i.to_string() == answer => Value::Integer(i),
+        _ => Value::String(answer.to_string())
+    };
+    let files = answers.entry(format!("day{}", day)).or_insert_with(|| Value::Table(Table::new()));
+    if let Value::Table(files) = files {
+        let parts = files.entry(filename).or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(parts) = parts {
+            parts.insert(format!("part{}", part), value);
+        }
+    }
+}
+
+/// The text recorded for an answer, or for the error a part failed with, which is expected to happen
+/// again for input such as an invalid example.
+pub fn text(found: Result<String, String>) -> String {
+    found.unwrap_or_else(|e| format!("error: {}", e))
+}
+
+/// Compares an answer, or the error a part failed with, to the recorded answer.
+pub fn status(expected: Option<String>, found: Result<String, String>) -> Status {
+    let found = text(found);
+    match expected {
+        Some(expected) if found.lines().eq(expected.lines()) => Status::Pass,
+        Some(expected) => Status::Fail { expected, found },
+        None => Status::Missing { found }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use toml::{Table, Value};
+use aoc_common::{Diagnostic, Report};
+use crate::days::Day;
+
+/// Slowdowns smaller than this are put down to noise rather than flagged, however large they are
+/// as a percentage, since the fastest steps take only a few microseconds.
+const NOISE: Duration = Duration::from_micros(100);
+
+/// The median time taken by each step of solving a day: parsing, then each part it has a solution for.
+pub struct Timings {
+    pub day: usize,
+    pub steps: Vec<(String, Duration)>
+}
+
+/// One step's time compared with the baseline, if the baseline has a time for it.
+pub struct Comparison {
+    pub step: String,
+    pub current: Duration,
+    pub baseline: Option<Duration>,
+    pub regressed: bool
+}
+
+/// Solves a day a number of times, keeping the median time of each step.
+pub fn measure(day: &Day, text: &str, iterations: usize) -> Result<Timings, Box<dyn Diagnostic>> {
+    let mut samples: Vec<(String, Vec<Duration>)> = Vec::new();
+    for _ in 0..iterations {
+        let mut report = Report::new(day.number);
+        (day.solve)(text, day.parts, &mut report)?;
+        for (step, elapsed) in report.timing {
+            match samples.iter_mut().find(|(s, _)| *s == step) {
+                Some((_, times)) => times.push(elapsed),
+                None => samples.push((step, vec![elapsed]))
+            }
+        }
+    }
+    let steps = samples.into_iter().map(|(step, mut times)| {
+        times.sort();
+        (step, times[times.len() / 2])
+    }).collect();
+    Ok(Timings { day: day.number, steps })
+}
+
+/// Reads a baseline file, which has a table of step times in nanoseconds for each day:
+///
+/// ```text
+/// [day8]
+/// parse = 365130
+/// part1 = 94018
+/// part2 = 471081133
+/// ```
+///
+/// A missing file is an empty baseline.
+pub fn load(path: &Path) -> Result<Table, String> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+    let text = fs::read_to_string(path).map_err(|e| format!("Error reading from {}: {}", path.display(), e))?;
+    text.parse().map_err(|e| format!("Error parsing {}: {}", path.display(), e))
+}
+
+pub fn save(path: &Path, baseline: &Table) -> Result<(), String> {
+    let text = format!("# Median time of each step in nanoseconds, written by `advent2021 bench --save`\n\n{}", baseline);
+    fs::write(path, text).map_err(|e| format!("Error writing to {}: {}", path.display(), e))
+}
+
+/// Replaces a day's times in the baseline.
+pub fn record(baseline: &mut Table, timings: &Timings) {
+    let steps = timings.steps.iter().map(|(step, elapsed)| (step.clone(), Value::Integer(elapsed.as_nanos() as i64))).collect();
+    baseline.insert(format!("day{}", timings.day), Value::Table(steps));
+}
+
+/// Compares each step of a day with the baseline, flagging those more than `threshold` percent slower.
+pub fn compare(timings: &Timings, baseline: &Table, threshold: f64) -> Vec<Comparison> {
+    let day = baseline.get(&format!("day{}", timings.day)).and_then(|d| d.as_table());
+    timings.steps.iter().map(|(step, current)| {
+        let baseline = day.and_then(|d| d.get(step)).and_then(|t| t.as_integer()).map(|t| Duration::from_nanos(t as u64));
+        Comparison {
+            step: step.clone(),
+            current: *current,
+            baseline,
+            regressed: baseline.is_some_and(|b| regressed(*current, b, threshold))
+        }
+    }).collect()
+}
+
+/// Whether a time is more than `threshold` percent slower than the baseline, and by more than the noise.
+pub fn regressed(current: Duration, baseline: Duration, threshold: f64) -> bool {
+    current > baseline + NOISE && change(current, baseline) > threshold
+}
+
+/// The percentage change from the baseline.
+pub fn change(current: Duration, baseline: Duration) -> f64 {
+    (current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64().max(1e-9) * 100.0
+}
\ No newline at end of file
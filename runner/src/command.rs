@@ -0,0 +1,231 @@
+use std::time::Duration;
+use aoc_common::Format;
+use aoc_common::input;
+
+pub const USAGE: &str = "Usage:
+    advent2021 run <day> [--part 1|2] [--format text|json] [<input>|-]
+    advent2021 run --all [--jobs <n>] [--timeout <seconds>] [--format text|json]
+    advent2021 bench <day>...|--all [--iterations <n>] [--baseline <file>] [--threshold <percent>] [--save]
+    advent2021 generate <day> [--seed <n>] [--size <n>]
+    advent2021 validate <day> [<input>|-]
+    advent2021 check <day>...|--all [--answers <file>]
+    advent2021 record <day>...|--all [--answers <file>]";
+
+/// How long `run --all` gives each day before abandoning it.
+pub const TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The baseline benchmark times are read from and saved to, relative to the current directory.
+pub const BASELINE: &str = "bench.toml";
+
+/// The answers checked and recorded for each input, relative to the current directory.
+pub const ANSWERS: &str = "answers.toml";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: usize,
+        part: Option<usize>,
+        filename: String,
+        format: Format
+    },
+    RunAll {
+        format: Format,
+        /// Worker threads, or one per CPU if not given
+        jobs: Option<usize>,
+        timeout: Duration
+    },
+    Bench {
+        days: Vec<usize>,
+        iterations: usize,
+        baseline: String,
+        threshold: f64,
+        save: bool
+    },
+    Generate {
+        day: usize,
+        seed: u64,
+        size: Option<usize>
+    },
+    Validate {
+        day: usize,
+        filename: String
+    },
+    Check {
+        days: Vec<usize>,
+        answers: String
+    },
+    Record {
+        days: Vec<usize>,
+        answers: String
+    }
+}
+
+impl Command {
+    pub fn parse(args: Vec<String>) -> Result<Self, String> {
+        let mut iter = args.into_iter();
+        match iter.next().as_deref() {
+            Some("run") => Self::parse_run(iter),
+            Some("bench") => Self::parse_bench(iter),
+            Some("generate") => Self::parse_generate(iter),
+            Some("validate") => Self::parse_validate(iter),
+            Some("check") => Self::parse_answers(iter).map(|(days, answers)| Self::Check { days, answers }),
+            Some("record") => Self::parse_answers(iter).map(|(days, answers)| Self::Record { days, answers }),
+            Some(other) => Err(format!("Unknown command: {}", other)),
+            None => Err("Please provide a command".to_string())
+        }
+    }
+
+    fn parse_run(mut iter: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut filename = None;
+        let mut all = false;
+        let mut format = Format::Text;
+        let mut jobs = None;
+        let mut timeout = None;
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--jobs" => {
+                    let value = iter.next().ok_or("Missing value for --jobs")?;
+                    jobs = Some(value.parse().ok().filter(|&n| n > 0).ok_or(format!("Invalid jobs: {}", value))?);
+                },
+                "--timeout" => {
+                    let value = iter.next().ok_or("Missing value for --timeout")?;
+                    let seconds = value.parse().ok().filter(|&t: &f64| t > 0.0).ok_or(format!("Invalid timeout: {}", value))?;
+                    timeout = Some(Duration::from_secs_f64(seconds));
+                },
+                "--part" => {
+                    let value = iter.next().ok_or("Missing value for --part")?;
+                    part = Some(match value.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        _ => return Err(format!("Invalid part: {}", value))
+                    });
+                },
+                "--format" => format = iter.next().ok_or("Missing value for --format")?.parse()?,
+                _ if day.is_none() && !all => day = Some(parse_day(&arg)?),
+                _ if filename.is_none() && !all => filename = Some(arg),
+                _ => return Err(format!("Unexpected argument: {}", arg))
+            }
+        }
+        if all {
+            if day.is_some() || part.is_some() {
+                return Err("--all cannot be combined with a day or part".to_string());
+            }
+            Ok(Self::RunAll { format, jobs, timeout: timeout.unwrap_or(TIMEOUT) })
+        } else {
+            if jobs.is_some() || timeout.is_some() {
+                return Err("--jobs and --timeout need --all".to_string());
+            }
+            Ok(Self::Run {
+                day: day.ok_or("Please provide a day")?,
+                part,
+                filename: filename.unwrap_or_else(|| input::STDIN.to_string()),
+                format
+            })
+        }
+    }
+
+    fn parse_bench(mut iter: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut days = Vec::new();
+        let mut all = false;
+        let mut iterations = 3;
+        let mut baseline = BASELINE.to_string();
+        let mut threshold = 10.0;
+        let mut save = false;
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--save" => save = true,
+                "--iterations" => {
+                    let value = iter.next().ok_or("Missing value for --iterations")?;
+                    iterations = value.parse().ok().filter(|&n| n > 0).ok_or(format!("Invalid iterations: {}", value))?;
+                },
+                "--baseline" => baseline = iter.next().ok_or("Missing value for --baseline")?,
+                "--threshold" => {
+                    let value = iter.next().ok_or("Missing value for --threshold")?;
+                    threshold = value.parse().ok().filter(|&t: &f64| t >= 0.0).ok_or(format!("Invalid threshold: {}", value))?;
+                },
+                _ => days.push(parse_day(&arg)?)
+            }
+        }
+        if all {
+            if !days.is_empty() {
+                return Err("--all cannot be combined with a day".to_string());
+            }
+            days = (1..=25).collect();
+        } else if days.is_empty() {
+            return Err("Please provide a day".to_string());
+        }
+        Ok(Self::Bench { days, iterations, baseline, threshold, save })
+    }
+
+    fn parse_generate(mut iter: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut seed = 0;
+        let mut size = None;
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let value = iter.next().ok_or("Missing value for --seed")?;
+                    seed = value.parse().map_err(|_| format!("Invalid seed: {}", value))?;
+                },
+                "--size" => {
+                    let value = iter.next().ok_or("Missing value for --size")?;
+                    size = Some(value.parse().ok().filter(|&n| n > 0).ok_or(format!("Invalid size: {}", value))?);
+                },
+                _ if day.is_none() => day = Some(parse_day(&arg)?),
+                _ => return Err(format!("Unexpected argument: {}", arg))
+            }
+        }
+        Ok(Self::Generate {
+            day: day.ok_or("Please provide a day")?,
+            seed,
+            size
+        })
+    }
+
+    fn parse_validate(iter: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut filename = None;
+        for arg in iter {
+            match arg {
+                _ if day.is_none() => day = Some(parse_day(&arg)?),
+                _ if filename.is_none() => filename = Some(arg),
+                _ => return Err(format!("Unexpected argument: {}", arg))
+            }
+        }
+        Ok(Self::Validate {
+            day: day.ok_or("Please provide a day")?,
+            filename: filename.unwrap_or_else(|| input::STDIN.to_string())
+        })
+    }
+
+    /// Parses the days and answers file for `check` and `record`.
+    fn parse_answers(mut iter: impl Iterator<Item = String>) -> Result<(Vec<usize>, String), String> {
+        let mut days = Vec::new();
+        let mut all = false;
+        let mut answers = ANSWERS.to_string();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--answers" => answers = iter.next().ok_or("Missing value for --answers")?,
+                _ => days.push(parse_day(&arg)?)
+            }
+        }
+        if all {
+            if !days.is_empty() {
+                return Err("--all cannot be combined with a day".to_string());
+            }
+            days = (1..=25).collect();
+        } else if days.is_empty() {
+            return Err("Please provide a day".to_string());
+        }
+        Ok((days, answers))
+    }
+}
+
+fn parse_day(arg: &str) -> Result<usize, String> {
+    arg.parse().ok().filter(|d| (1..=25).contains(d)).ok_or(format!("Invalid day: {}", arg))
+}
\ No newline at end of file
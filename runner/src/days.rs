@@ -0,0 +1,84 @@
+use aoc_common::{Answer, Diagnostic, Report};
+
+/// Parses a day's input and solves the requested parts, recording the time taken by each step in the report.
+pub type Solve = fn(&str, &[usize], &mut Report) -> Result<(), Box<dyn Diagnostic>>;
+
+/// Builds a `Day` from a day's crate, listing the part functions it has a working solution for.
+macro_rules! day {
+    ($number:literal, $day:ident $(, $part:literal => $f:ident)*) => {
+        Day {
+            number: $number,
+            parts: &[$($part),*],
+            generate: $day::generate::generate,
+            default_size: $day::generate::DEFAULT_SIZE,
+            validate: |text| $day::validate(text).into_iter().map(|e| Box::new(e) as Box<dyn Diagnostic>).collect(),
+            solve: |text, _parts, report| {
+                let _input = report.parse(|| $day::parse(text))?;
+                $(
+                    if _parts.contains(&$part) {
+                        report.part($part, || $day::$f(&_input));
+                    }
+                )*
+                Ok(())
+            }
+        }
+    }
+}
+
+pub struct Day {
+    pub number: usize,
+    pub parts: &'static [usize],
+    pub solve: Solve,
+    /// Generates valid random input from a seed and a size, whose meaning depends on the day.
+    pub generate: fn(u64, usize) -> String,
+    pub default_size: usize,
+    /// Checks input without solving it, returning every problem found.
+    pub validate: fn(&str) -> Vec<Box<dyn Diagnostic>>
+}
+
+impl Day {
+    /// Solves a single part, including parsing the text, if the day has a solution for it.
+    pub fn part(&self, part: usize) -> Option<impl Fn(&str) -> Result<Answer, Box<dyn Diagnostic>> + '_> {
+        self.parts.contains(&part).then_some(move |text: &str| {
+            let mut report = Report::new(self.number);
+            (self.solve)(text, &[part], &mut report)?;
+            match report.answers.pop() {
+                Some((_, answer)) => Ok(answer),
+                None => Err(report.errors.remove(0).1.into())
+            }
+        })
+    }
+}
+
+/// Every day's solvers. Parts without a working solution in this repo are left out.
+pub const DAYS: [Day; 25] = [
+    day!(1, day1, 1 => part1, 2 => part2),
+    day!(2, day2, 1 => part1, 2 => part2),
+    day!(3, day3, 1 => part1, 2 => part2),
+    day!(4, day4, 1 => part1, 2 => part2),
+    day!(5, day5, 1 => part1, 2 => part2),
+    day!(6, day6, 1 => part1, 2 => part2),
+    day!(7, day7, 1 => part1, 2 => part2),
+    day!(8, day8, 1 => part1, 2 => part2),
+    day!(9, day9, 1 => part1, 2 => part2),
+    day!(10, day10, 1 => part1, 2 => part2),
+    day!(11, day11, 1 => part1, 2 => part2),
+    day!(12, day12, 1 => part1, 2 => part2),
+    day!(13, day13, 1 => part1, 2 => part2),
+    day!(14, day14, 1 => part1, 2 => part2),
+    day!(15, day15, 1 => part1, 2 => part2),
+    day!(16, day16, 1 => part1, 2 => part2),
+    day!(17, day17, 1 => part1, 2 => part2),
+    day!(18, day18, 1 => part1, 2 => part2),
+    day!(19, day19, 1 => part1, 2 => part2),
+    day!(20, day20, 1 => part1, 2 => part2),
+    day!(21, day21, 1 => part1, 2 => part2),
+    day!(22, day22, 1 => part1, 2 => part2),
+    day!(23, day23, 2 => part2),
+    day!(24, day24),
+    day!(25, day25, 1 => part1)
+];
+
+pub fn find(number: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+use crate::days::Day;
+
+/// The answers recorded for one example input in a day's `expected.toml`. Parts left out aren't checked,
+/// and an example with an `error` is expected to fail with that message instead.
+pub struct Example {
+    pub filename: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub error: Option<String>
+}
+
+impl Example {
+    fn expected(&self, part: usize) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None
+        }
+    }
+}
+
+/// Every example input file in a directory: `test.txt`, `test2.txt` and so on.
+pub fn example_files(directory: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(directory).into_iter().flatten().flatten()
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|f| f.starts_with("test") && f.ends_with(".txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// The directory of a day's crate, which holds its inputs.
+pub fn directory(day: &Day) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day{}", day.number))
+}
+
+/// Reads the examples recorded in `expected.toml`, which has a table of answers for each example file.
+pub fn load(directory: &Path) -> Result<Vec<Example>, String> {
+    let path = directory.join("expected.toml");
+    let text = fs::read_to_string(&path).map_err(|e| format!("Error reading from {}: {}", path.display(), e))?;
+    let table: Table = text.parse().map_err(|e| format!("Error parsing {}: {}", path.display(), e))?;
+    let mut examples = Vec::new();
+    for (filename, answers) in table {
+        let answer = |key: &str| match answers.get(key) {
+            None => Ok(None),
+            Some(Value::Integer(i)) => Ok(Some(i.to_string())),
+            Some(Value::String(s)) => Ok(Some(s.clone())),
+            Some(other) => Err(format!("Expected a number or string for {} of {}, found {}", key, filename, other))
+        };
+        examples.push(Example {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+            error: answer("error")?,
+            filename
+        });
+    }
+    Ok(examples)
+}
+
+/// Runs a day's solver against every example input, returning a description of each answer which
+/// doesn't match, of each valid example which fails validation, and of each example file without
+/// recorded answers.
+pub fn check(day: &Day) -> Vec<String> {
+    let directory = directory(day);
+    let examples = match load(&directory) {
+        Ok(examples) => examples,
+        Err(e) => return vec![e]
+    };
+    let mut failures = Vec::new();
+    for filename in example_files(&directory) {
+        if !examples.iter().any(|e| e.filename == filename) {
+            failures.push(format!("Day {} {}: no answers in expected.toml", day.number, filename));
+        }
+    }
+    for example in &examples {
+        let path = directory.join(&example.filename);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                failures.push(format!("Day {} {}: {}", day.number, example.filename, e));
+                continue;
+            }
+        };
+        if example.error.is_none() {
+            for problem in (day.validate)(&text) {
+                failures.push(format!("Day {} {}: fails validation: {}", day.number, example.filename, problem));
+            }
+        }
+        for part in [1, 2] {
+            let solve = match day.part(part) {
+                Some(solve) => solve,
+                None => continue
+            };
+            let found = match solve(&text) {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("error: {}", e)
+            };
+            let expected = match (&example.error, example.expected(part)) {
+                (Some(error), _) => format!("error: {}", error),
+                (None, Some(answer)) => answer.clone(),
+                (None, None) => continue
+            };
+            if !found.lines().eq(expected.lines()) {
+                failures.push(format!("Day {} part {} {}: expected {}, found {}", day.number, part, example.filename, expected, found));
+            }
+        }
+    }
+    failures
+}
\ No newline at end of file
@@ -0,0 +1,279 @@
+use std::env;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Instant;
+use aoc_common::{Format, Report, render};
+use aoc_common::input;
+use crate::answers::Status;
+use crate::command::{Command, USAGE};
+use crate::days::{Day, DAYS};
+
+mod answers;
+mod bench;
+mod command;
+mod days;
+mod parallel;
+
+#[cfg(test)]
+mod examples;
+#[cfg(test)]
+mod tests;
+
+fn main() {
+    let command = match Command::parse(env::args().skip(1).collect()) {
+        Ok(command) => command,
+        Err(e) => {
+            println!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let success = match command {
+        Command::Run { day, part, filename, format } => {
+            let parts = match part {
+                Some(p) => vec![p],
+                None => vec![1, 2]
+            };
+            let (success, report) = run_day(days::find(day).unwrap(), &parts, &filename, format == Format::Text);
+            if format == Format::Json {
+                println!("{}", report.to_json());
+            }
+            success
+        },
+        Command::RunAll { format, jobs, timeout } => {
+            let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            let start = Instant::now();
+            let runs = parallel::run(&DAYS, jobs, timeout, |day| run_day(day, &[1, 2], &format!("day{}/input.txt", day.number), false));
+            match format {
+                Format::Text => {
+                    println!("{}", parallel::summary(&runs));
+                    println!("{} days on {} thread(s) in {:.2?}", runs.len(), jobs, start.elapsed());
+                },
+                Format::Json => println!("[{}]", runs.iter().map(|r| r.report().to_json()).collect::<Vec<String>>().join(","))
+            }
+            runs.iter().all(|r| r.success())
+        },
+        Command::Bench { days, iterations, baseline, threshold, save } => run_bench(&days, iterations, Path::new(&baseline), threshold, save),
+        Command::Generate { day, seed, size } => {
+            let day = days::find(day).unwrap();
+            println!("{}", (day.generate)(seed, size.unwrap_or(day.default_size)));
+            true
+        },
+        Command::Validate { day, filename } => run_validate(days::find(day).unwrap(), &filename),
+        Command::Check { days, answers } => run_check(&days, Path::new(&answers)),
+        Command::Record { days, answers } => run_record(&days, Path::new(&answers))
+    };
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Runs the given parts of a day against an input file, printing each answer with its wall-clock time
+/// if `print` is set, and collecting them into the returned report. Diagnostics always go to stderr.
+/// Returns false if the input couldn't be read or any part failed.
+fn run_day(day: &Day, parts: &[usize], filename: &str, print: bool) -> (bool, Report) {
+    let mut report = Report::new(day.number);
+    let name = input::name(filename);
+    let text = match input::read(filename) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Day {}: Error reading from {}: {}", day.number, name, e);
+            return (false, report);
+        }
+    };
+    let start = Instant::now();
+    for &part in parts {
+        match day.part(part) {
+            Some(solve) => {
+                let part_start = Instant::now();
+                let result = solve(&text);
+                let elapsed = part_start.elapsed();
+                match result {
+                    Ok(answer) => {
+                        if print {
+                            println!("Day {} part {}: {} ({:?})", day.number, part, answer, elapsed);
+                        }
+                        report.answer(part, answer, elapsed);
+                    },
+                    Err(e) => {
+                        eprintln!("Day {} part {}: FAILED ({:?})", day.number, part, elapsed);
+                        eprintln!("{}", render(e.as_ref(), name, &text));
+                        report.error(part, e.to_string(), elapsed);
+                    }
+                }
+            },
+            None => if print {
+                println!("Day {} part {}: not solved", day.number, part);
+            }
+        }
+    }
+    report.time("total", start.elapsed());
+    (report.errors.is_empty(), report)
+}
+
+/// Benchmarks days against their `dayN/input.txt`, printing the median time of each step alongside the
+/// baseline's. Returns false if any day failed or, unless saving a new baseline, any step regressed.
+fn run_bench(days: &[usize], iterations: usize, path: &Path, threshold: f64, save: bool) -> bool {
+    let mut baseline = match bench::load(path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let mut success = true;
+    let mut regressions = 0;
+    for &number in days {
+        let day = days::find(number).unwrap();
+        let filename = format!("day{}/input.txt", number);
+        let text = match input::read(&filename) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Day {}: Error reading from {}: {}", number, filename, e);
+                success = false;
+                continue;
+            }
+        };
+        let timings = match bench::measure(day, &text, iterations) {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("Day {}: FAILED", number);
+                eprintln!("{}", render(e.as_ref(), &filename, &text));
+                success = false;
+                continue;
+            }
+        };
+        for comparison in bench::compare(&timings, &baseline, threshold) {
+            match comparison.baseline {
+                Some(previous) => println!("Day {} {}: {:?} (baseline {:?}, {:+.1}%){}", number, comparison.step, comparison.current, previous,
+                    bench::change(comparison.current, previous), if comparison.regressed { " REGRESSED" } else { "" }),
+                None => println!("Day {} {}: {:?} (no baseline)", number, comparison.step, comparison.current)
+            }
+            if comparison.regressed {
+                regressions += 1;
+            }
+        }
+        if save {
+            bench::record(&mut baseline, &timings);
+        }
+    }
+    if regressions > 0 {
+        println!("{} step(s) regressed by more than {}%", regressions, threshold);
+    }
+    if save {
+        match bench::save(path, &baseline) {
+            Ok(()) => println!("Saved baseline to {}", path.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                success = false;
+            }
+        }
+    }
+    success && (save || regressions == 0)
+}
+
+/// Checks an input file against a day's format without solving it, printing a diagnostic for every problem.
+/// Returns false if the file couldn't be read or has any problems.
+fn run_validate(day: &Day, filename: &str) -> bool {
+    let name = input::name(filename);
+    let text = match input::read(filename) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Day {}: Error reading from {}: {}", day.number, name, e);
+            return false;
+        }
+    };
+    let problems = (day.validate)(&text);
+    for problem in &problems {
+        eprintln!("{}", render(problem.as_ref(), name, &text));
+    }
+    if problems.is_empty() {
+        println!("Day {}: {} is valid", day.number, name);
+    } else {
+        println!("Day {}: {} problem(s) in {}", day.number, problems.len(), name);
+    }
+    problems.is_empty()
+}
+
+/// Checks every part of the given days against the recorded answers for their input files, printing
+/// PASS, FAIL or MISSING for each. Returns false if an answers file or input couldn't be read, or any part failed.
+fn run_check(days: &[usize], path: &Path) -> bool {
+    let answers = match answers::load(path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let mut success = true;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for &number in days {
+        let day = days::find(number).unwrap();
+        let path = format!("day{}/{}", number, answers::INPUT);
+        let text = match input::read(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Day {}: Error reading from {}: {}", number, path, e);
+                success = false;
+                continue;
+            }
+        };
+        for (part, found) in answers::solve(day, &text) {
+            match answers::status(answers::expected(&answers, number, answers::INPUT, part), found) {
+                Status::Pass => {
+                    println!("Day {} part {}: PASS", number, part);
+                    passed += 1;
+                },
+                Status::Fail { expected, found } => {
+                    println!("Day {} part {}: FAIL (expected {}, found {})", number, part, expected, found);
+                    failed += 1;
+                },
+                Status::Missing { found } => {
+                    println!("Day {} part {}: MISSING (found {})", number, part, found);
+                    missing += 1;
+                }
+            }
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    success && failed == 0
+}
+
+/// Records the current answer to every part of the given days for their input files, replacing
+/// any previous answers. A part which fails has its error recorded, to be expected next time. Returns
+/// false if anything couldn't be read or saved.
+fn run_record(days: &[usize], path: &Path) -> bool {
+    let mut answers = match answers::load(path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let mut success = true;
+    for &number in days {
+        let day = days::find(number).unwrap();
+        let path = format!("day{}/{}", number, answers::INPUT);
+        let text = match input::read(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Day {}: Error reading from {}: {}", number, path, e);
+                success = false;
+                continue;
+            }
+        };
+        for (part, found) in answers::solve(day, &text) {
+            let answer = answers::text(found);
+            println!("Day {} part {}: {}", number, part, answer);
+            answers::record(&mut answers, number, answers::INPUT, part, &answer);
+        }
+    }
+    match answers::save(path, &answers) {
+        Ok(()) => println!("Saved answers to {}", path.display()),
+        Err(e) => {
+            eprintln!("{}", e);
+            success = false;
+        }
+    }
+    success
+}
\ No newline at end of file
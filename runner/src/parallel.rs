@@ -0,0 +1,124 @@
+use std::cmp::Reverse;
+use std::sync::Mutex;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use aoc_common::{Answer, Report};
+use crate::days::Day;
+
+/// Solves a day, returning whether every part succeeded along with its report.
+pub type SolveDay = fn(&'static Day) -> (bool, Report);
+
+/// How running a day turned out.
+pub enum Outcome {
+    Finished { success: bool, report: Report },
+    Panicked(String),
+    TimedOut
+}
+
+pub struct DayRun {
+    pub day: &'static Day,
+    pub outcome: Outcome,
+    pub elapsed: Duration
+}
+
+/// Solves days on a pool of `jobs` worker threads, returning how each went in the order given. Each day
+/// runs on a thread of its own, so a panic only fails that day. A day still running after `timeout` is
+/// abandoned: its thread can't be stopped, but is left behind and doesn't hold up the rest.
+pub fn run(days: &'static [Day], jobs: usize, timeout: Duration, solve: SolveDay) -> Vec<DayRun> {
+    let queue = Mutex::new(days.iter());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                while let Some(day) = queue.lock().unwrap().next() {
+                    let _ = sender.send(run_day(day, timeout, solve));
+                }
+            });
+        }
+    });
+    drop(sender);
+    let mut runs: Vec<DayRun> = receiver.into_iter().collect();
+    runs.sort_by_key(|r| days.iter().position(|d| d.number == r.day.number));
+    runs
+}
+
+fn run_day(day: &'static Day, timeout: Duration, solve: SolveDay) -> DayRun {
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .name(format!("day{}", day.number))
+        .spawn(move || {
+            let _ = sender.send(solve(day));
+        })
+        .expect("failed to spawn a thread");
+    let outcome = match receiver.recv_timeout(timeout) {
+        Ok((success, report)) => Outcome::Finished { success, report },
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => Outcome::Panicked(payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string())),
+            Ok(()) => Outcome::Panicked("finished without a result".to_string())
+        }
+    };
+    DayRun { day, outcome, elapsed: start.elapsed() }
+}
+
+impl DayRun {
+    pub fn success(&self) -> bool {
+        matches!(self.outcome, Outcome::Finished { success: true, .. })
+    }
+
+    /// The day's report, with an error for each part if it panicked or timed out.
+    pub fn report(&self) -> Report {
+        let message = match &self.outcome {
+            Outcome::Finished { report, .. } => return report.clone(),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+            Outcome::TimedOut => format!("timed out after {:.2?}", self.elapsed)
+        };
+        let mut report = Report::new(self.day.number);
+        for &part in self.day.parts {
+            report.error(part, message.clone(), self.elapsed);
+        }
+        report
+    }
+}
+
+/// A table of each day's status, answers and wall-clock time, most expensive first.
+pub fn summary(runs: &[DayRun]) -> String {
+    let mut runs: Vec<&DayRun> = runs.iter().collect();
+    runs.sort_by_key(|r| Reverse(r.elapsed));
+    let mut rows = vec![["Day".to_string(), "Time".to_string(), "Status".to_string(), "Part 1".to_string(), "Part 2".to_string()]];
+    for run in runs {
+        let report = run.report();
+        let status = match &run.outcome {
+            Outcome::Finished { success: true, .. } => "ok",
+            Outcome::Finished { success: false, .. } => "FAILED",
+            Outcome::Panicked(_) => "PANICKED",
+            Outcome::TimedOut => "TIMEOUT"
+        };
+        let part = |part: usize| match report.answers.iter().find(|(p, _)| *p == part) {
+            Some((_, answer)) => cell(answer),
+            None if report.errors.iter().any(|(p, _)| *p == part) => "error".to_string(),
+            None => "-".to_string()
+        };
+        rows.push([run.day.number.to_string(), format!("{:.2?}", run.elapsed), status.to_string(), part(1), part(2)]);
+    }
+    let widths: Vec<usize> = (0..5).map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0)).collect();
+    rows.iter().map(|row| {
+        let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, width)| format!("{:width$}", cell, width = width)).collect();
+        cells.join("  ").trim_end().to_string()
+    }).collect::<Vec<String>>().join("\n")
+}
+
+/// An answer fitted on one line of the table. Pictures such as day 13's are too big to show.
+fn cell(answer: &Answer) -> String {
+    let text = answer.to_string();
+    match text.lines().count() {
+        0 | 1 => text,
+        n => format!("({} lines)", n)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use super::*;
+use std::time::Duration;
+use aoc_common::Format;
+use crate::bench::Timings;
+
+fn parse(args: &[&str]) -> Result<Command, String> {
+    Command::parse(args.iter().map(|a| a.to_string()).collect())
+}
+
+#[test]
+fn parse_run_day() {
+    assert_eq!(parse(&["run", "5", "input.txt"]), Ok(Command::Run { day: 5, part: None, filename: "input.txt".to_string(), format: Format::Text }));
+    assert_eq!(parse(&["run", "5", "--part", "2", "input.txt"]), Ok(Command::Run { day: 5, part: Some(2), filename: "input.txt".to_string(), format: Format::Text }));
+    assert_eq!(parse(&["run", "5"]), Ok(Command::Run { day: 5, part: None, filename: "-".to_string(), format: Format::Text }));
+    assert_eq!(parse(&["run", "5", "input.txt", "--format", "json"]), Ok(Command::Run { day: 5, part: None, filename: "input.txt".to_string(), format: Format::Json }));
+}
+
+#[test]
+fn parse_run_all() {
+    assert_eq!(parse(&["run", "--all"]), Ok(Command::RunAll { format: Format::Text, jobs: None, timeout: command::TIMEOUT }));
+    assert_eq!(parse(&["run", "--all", "--format", "json"]), Ok(Command::RunAll { format: Format::Json, jobs: None, timeout: command::TIMEOUT }));
+    assert_eq!(parse(&["run", "--all", "--jobs", "4", "--timeout", "2.5"]),
+        Ok(Command::RunAll { format: Format::Text, jobs: Some(4), timeout: Duration::from_millis(2500) }));
+    assert!(parse(&["run", "--all", "--part", "1"]).is_err());
+    assert_eq!(parse(&["run", "--all", "--jobs", "0"]), Err("Invalid jobs: 0".to_string()));
+    assert_eq!(parse(&["run", "5", "--timeout", "10"]), Err("--jobs and --timeout need --all".to_string()));
+}
+
+#[test]
+fn parse_invalid() {
+    assert_eq!(parse(&["run", "26", "input.txt"]), Err("Invalid day: 26".to_string()));
+    assert_eq!(parse(&["run", "5", "--part", "3", "input.txt"]), Err("Invalid part: 3".to_string()));
+    assert_eq!(parse(&["run", "--all", "--format", "xml"]), Err("Invalid format: xml".to_string()));
+    assert_eq!(parse(&["walk"]), Err("Unknown command: walk".to_string()));
+}
+
+#[test]
+fn parse_bench() {
+    assert_eq!(parse(&["bench", "8", "20"]), Ok(Command::Bench { days: vec![8, 20], iterations: 3, baseline: "bench.toml".to_string(), threshold: 10.0, save: false }));
+    assert_eq!(parse(&["bench", "8", "--iterations", "10", "--baseline", "b.toml", "--threshold", "25", "--save"]),
+        Ok(Command::Bench { days: vec![8], iterations: 10, baseline: "b.toml".to_string(), threshold: 25.0, save: true }));
+    assert!(matches!(parse(&["bench", "--all"]), Ok(Command::Bench { days, .. }) if days.len() == 25));
+    assert_eq!(parse(&["bench", "--all", "8"]), Err("--all cannot be combined with a day".to_string()));
+    assert_eq!(parse(&["bench", "8", "--iterations", "0"]), Err("Invalid iterations: 0".to_string()));
+    assert_eq!(parse(&["bench"]), Err("Please provide a day".to_string()));
+}
+
+#[test]
+fn parse_generate() {
+    assert_eq!(parse(&["generate", "4"]), Ok(Command::Generate { day: 4, seed: 0, size: None }));
+    assert_eq!(parse(&["generate", "4", "--seed", "7", "--size", "3"]), Ok(Command::Generate { day: 4, seed: 7, size: Some(3) }));
+    assert_eq!(parse(&["generate", "4", "--seed", "x"]), Err("Invalid seed: x".to_string()));
+    assert_eq!(parse(&["generate", "4", "--size", "0"]), Err("Invalid size: 0".to_string()));
+}
+
+#[test]
+fn parse_validate() {
+    assert_eq!(parse(&["validate", "4", "test.txt"]), Ok(Command::Validate { day: 4, filename: "test.txt".to_string() }));
+    assert_eq!(parse(&["validate", "4"]), Ok(Command::Validate { day: 4, filename: "-".to_string() }));
+    assert_eq!(parse(&["validate"]), Err("Please provide a day".to_string()));
+}
+
+#[test]
+fn parse_check_and_record() {
+    assert_eq!(parse(&["check", "3", "4"]), Ok(Command::Check { days: vec![3, 4], answers: "answers.toml".to_string() }));
+    assert_eq!(parse(&["record", "3", "--answers", "a.toml"]), Ok(Command::Record { days: vec![3], answers: "a.toml".to_string() }));
+    assert!(matches!(parse(&["check", "--all"]), Ok(Command::Check { days, .. }) if days.len() == 25));
+    assert_eq!(parse(&["record"]), Err("Please provide a day".to_string()));
+}
+
+#[test]
+fn answers_record_and_check() {
+    let mut answers = toml::Table::new();
+    answers::record(&mut answers, 13, answers::INPUT, 1, "17");
+    answers::record(&mut answers, 13, answers::INPUT, 2, "#.\n.#");
+    let answers: toml::Table = answers.to_string().parse().unwrap();
+    assert_eq!(answers::status(answers::expected(&answers, 13, answers::INPUT, 1), Ok("17".to_string())), answers::Status::Pass);
+    assert_eq!(answers::status(answers::expected(&answers, 13, answers::INPUT, 2), Ok("#.\n.#".to_string())), answers::Status::Pass);
+    assert_eq!(answers::status(answers::expected(&answers, 13, answers::INPUT, 1), Err("oops".to_string())),
+        answers::Status::Fail { expected: "17".to_string(), found: "error: oops".to_string() });
+    assert_eq!(answers::status(answers::expected(&answers, 14, answers::INPUT, 1), Ok("5".to_string())), answers::Status::Missing { found: "5".to_string() });
+}
+
+#[test]
+fn bench_compare_with_baseline() {
+    let baseline: toml::Table = "[day8]\nparse = 1000000\npart1 = 1000\n".parse().unwrap();
+    let timings = Timings {
+        day: 8,
+        steps: vec![("parse".to_string(), Duration::from_millis(2)), ("part1".to_string(), Duration::from_micros(50)), ("part2".to_string(), Duration::from_secs(1))]
+    };
+    let comparisons = bench::compare(&timings, &baseline, 50.0);
+    assert_eq!(comparisons.iter().map(|c| c.regressed).collect::<Vec<_>>(), vec![true, false, false]);
+    assert_eq!(comparisons[2].baseline, None);
+    assert!(!bench::regressed(Duration::from_millis(2), Duration::from_millis(2), 0.0));
+    assert!(!bench::regressed(Duration::from_millis(14), Duration::from_millis(10), 50.0));
+}
+
+#[test]
+fn parallel_isolates_panics_and_timeouts() {
+    fn solve(day: &'static Day) -> (bool, aoc_common::Report) {
+        match day.number {
+            17 => panic!("probe lost"),
+            19 => {
+                std::thread::sleep(Duration::from_secs(10));
+                (true, aoc_common::Report::new(19))
+            },
+            n => (n != 3, aoc_common::Report::new(n))
+        }
+    }
+    let runs = parallel::run(&DAYS, 4, Duration::from_millis(500), solve);
+    assert_eq!(runs.iter().map(|r| r.day.number).collect::<Vec<_>>(), (1..=25).collect::<Vec<_>>());
+    assert!(matches!(&runs[16].outcome, parallel::Outcome::Panicked(message) if message == "probe lost"));
+    assert!(matches!(runs[18].outcome, parallel::Outcome::TimedOut));
+    assert_eq!(runs.iter().filter(|r| !r.success()).map(|r| r.day.number).collect::<Vec<_>>(), vec![3, 17, 19]);
+    assert_eq!(runs[16].report().errors, vec![(1, "panicked: probe lost".to_string()), (2, "panicked: probe lost".to_string())]);
+    assert!(parallel::summary(&runs).lines().nth(1).unwrap().starts_with("19 "));
+}
+
+/// Checks every example input of a day against its `expected.toml`.
+macro_rules! examples {
+    ($($name:ident: $day:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                let failures = crate::examples::check(days::find($day).unwrap());
+                assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+            }
+        )*
+    }
+}
+
+examples!(
+    day1_examples: 1, day2_examples: 2, day3_examples: 3, day4_examples: 4, day5_examples: 5,
+    day6_examples: 6, day7_examples: 7, day8_examples: 8, day9_examples: 9, day10_examples: 10,
+    day11_examples: 11, day12_examples: 12, day13_examples: 13, day14_examples: 14, day15_examples: 15,
+    day16_examples: 16, day17_examples: 17, day18_examples: 18, day19_examples: 19, day20_examples: 20,
+    day21_examples: 21, day22_examples: 22, day23_examples: 23, day24_examples: 24, day25_examples: 25
+);
+
+/// Validates and solves a few random inputs from a day's generator, small enough to keep the slow days quick.
+fn solve_generated(day: &Day) {
+    let (size, seeds) = match day.number {
+        19 => (3, 2),
+        21 => (2, 1),
+        _ => (day.default_size.min(20), 3)
+    };
+    for seed in 0..seeds {
+        let text = (day.generate)(seed, size);
+        assert_eq!(text, (day.generate)(seed, size), "day {} seed {} isn't reproducible", day.number, seed);
+        if let Some(problem) = (day.validate)(&text).first() {
+            panic!("day {} seed {} fails validation: {}\n{}", day.number, seed, problem, text);
+        }
+        let mut report = Report::new(day.number);
+        if let Err(e) = (day.solve)(&text, day.parts, &mut report) {
+            panic!("day {} seed {}: {}\n{}", day.number, seed, e, text);
+        }
+        assert_eq!(report.answers.len(), day.parts.len());
+    }
+}
+
+macro_rules! generated {
+    ($($name:ident: $day:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                solve_generated(days::find($day).unwrap());
+            }
+        )*
+    }
+}
+
+generated!(
+    day1_generated: 1, day2_generated: 2, day3_generated: 3, day4_generated: 4, day5_generated: 5,
+    day6_generated: 6, day7_generated: 7, day8_generated: 8, day9_generated: 9, day10_generated: 10,
+    day11_generated: 11, day12_generated: 12, day13_generated: 13, day14_generated: 14, day15_generated: 15,
+    day16_generated: 16, day17_generated: 17, day18_generated: 18, day19_generated: 19, day20_generated: 20,
+    day21_generated: 21, day22_generated: 22, day23_generated: 23, day24_generated: 24, day25_generated: 25
+);